@@ -1,19 +1,28 @@
 #![allow(dead_code)]
+use std::{collections::HashSet, iter};
+
 use crate::{
+    board::Board,
     character_filter::filter_character,
     data_model::{
-        Ability, AreaEffect, Character, Condition, ConditionEffect, EffectOnCharacter,
-        ModifyGainedConditions, TriggeredAbilities,
+        AreaEffect, Character, CharacterId, Condition, ConditionEffect, Death, EffectOnCharacter,
+        ModifyGainedConditions, Trigger,
     },
-    hex_grid::{PosAxial, pos_in_area},
+    game_event::{GameEvent, Observer},
+    hex_grid::{PosAxial, distance, pos_in_area},
+    movement::move_one_space,
+    registry::Registry,
     turn_stats::TurnStat,
 };
 
 pub fn apply_area_effects<'b, C, E>(
     effects: E,
     target: &PosAxial,
+    board: &Board,
     characters: &mut C,
     source_character: &mut Character,
+    registry: &Registry,
+    observer: &mut dyn Observer,
 ) where
     for<'c> &'c mut C: IntoIterator<Item = &'c mut Character>,
     E: Iterator<Item = &'b AreaEffect>,
@@ -22,8 +31,11 @@ pub fn apply_area_effects<'b, C, E>(
         apply_area_effect(
             effect,
             target,
+            board,
             source_character,
             (&mut *characters).into_iter(),
+            registry,
+            observer,
         );
     }
 }
@@ -31,116 +43,310 @@ pub fn apply_area_effects<'b, C, E>(
 pub fn apply_area_effect<'a, 'b, C>(
     area_effect: &AreaEffect,
     target: &PosAxial,
+    board: &Board,
     source_character: &mut Character,
     other_characters: C,
+    registry: &Registry,
+    observer: &mut dyn Observer,
 ) where
     C: Iterator<Item = &'a mut Character>,
 {
-    if pos_in_area(&source_character.pos, &area_effect.area, target)
+    let mut other_characters: Vec<_> = other_characters.collect();
+    apply_area_effect_to_characters(
+        area_effect,
+        target,
+        board,
+        source_character,
+        &mut other_characters,
+        registry,
+        observer,
+    );
+}
+
+fn apply_area_effect_to_characters(
+    area_effect: &AreaEffect,
+    target: &PosAxial,
+    board: &Board,
+    source_character: &mut Character,
+    other_characters: &mut [&mut Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    let mut occupied: HashSet<PosAxial> = other_characters
+        .iter()
+        .map(|character| &**character)
+        .chain(iter::once(&*source_character))
+        .filter(|character| character.is_alive())
+        .map(|character| character.pos.clone())
+        .collect();
+
+    if source_character.is_alive()
+        && pos_in_area(&source_character.pos, &area_effect.area, target)
         && area_effect
             .filter
             .as_ref()
             .is_none_or(|filter| filter_character(source_character, filter, source_character))
     {
         for effect in &area_effect.effects {
-            apply_effect_to_character_with_same_source_character(effect, source_character);
+            apply_effect_to_character_with_same_source_character(
+                effect,
+                source_character,
+                registry,
+                observer,
+            );
         }
     }
-    for character in other_characters
-        .into_iter()
-        .filter(|character| pos_in_area(&character.pos, &area_effect.area, target))
-    {
+    let mut kills = Vec::new();
+    for character in other_characters.iter_mut().filter(|character| {
+        character.is_alive() && pos_in_area(&character.pos, &area_effect.area, target)
+    }) {
         if area_effect
             .filter
             .as_ref()
             .is_none_or(|filter| filter_character(character, filter, source_character))
         {
             for effect in &area_effect.effects {
-                apply_effect_to_character(effect, character, source_character);
+                apply_effect_to_character(
+                    effect,
+                    character,
+                    source_character,
+                    board,
+                    &mut occupied,
+                    registry,
+                    observer,
+                );
+            }
+            if let Some(death) = &character.death
+                && death.killer == Some(source_character.id)
+            {
+                kills.push((character.pos.clone(), death.overkill));
             }
         }
     }
+    for (pos, overkill) in kills {
+        for on_kill in &area_effect.on_kill {
+            apply_area_effect_to_characters(
+                &with_overkill(on_kill, overkill),
+                &pos,
+                board,
+                source_character,
+                other_characters,
+                registry,
+                observer,
+            );
+        }
+    }
+}
+
+fn with_overkill(area_effect: &AreaEffect, overkill: usize) -> AreaEffect {
+    AreaEffect {
+        effects: area_effect
+            .effects
+            .iter()
+            .map(|effect| match effect {
+                EffectOnCharacter::OverkillDamage => EffectOnCharacter::Damage(overkill),
+                effect => effect.clone(),
+            })
+            .collect(),
+        ..area_effect.clone()
+    }
 }
 
 pub fn apply_effect_to_character(
     effect: &EffectOnCharacter,
     character: &mut Character,
     source_character: &mut Character,
+    board: &Board,
+    occupied: &mut HashSet<PosAxial>,
+    registry: &Registry,
+    observer: &mut dyn Observer,
 ) {
     match effect {
         EffectOnCharacter::Damage(damage) => {
-            push_triggered_abilities(source_character, |x| x.attack_action);
+            push_triggered_abilities(source_character, Trigger::AttackAction, registry, observer);
             let net_damage = net_damage(*damage, character, source_character);
-            deal_damage(net_damage, character);
+            deal_damage_from(net_damage, character, source_character, registry, observer);
             *source_character
                 .turn_stats
                 .get_current_mut(TurnStat::AttackActions) += 1;
-            deal_damage(
+            deal_damage_from(
                 character.conditions.get(&Condition::Retaliate),
                 source_character,
+                character,
+                registry,
+                observer,
             );
         }
         EffectOnCharacter::DamageWithLifesteal(damage) => {
-            push_triggered_abilities(source_character, |x| x.attack_action);
+            push_triggered_abilities(source_character, Trigger::AttackAction, registry, observer);
             let net_damage = net_damage(*damage, character, source_character);
-            deal_damage(net_damage, character);
+            deal_damage_from(net_damage, character, source_character, registry, observer);
             *source_character
                 .turn_stats
                 .get_current_mut(TurnStat::AttackActions) += 1;
-            deal_damage(
+            deal_damage_from(
                 character.conditions.get(&Condition::Retaliate),
                 source_character,
+                character,
+                registry,
+                observer,
             );
-            restore_health(net_damage, source_character);
+            restore_health(net_damage, source_character, observer);
         }
         EffectOnCharacter::Heal(health) => {
-            restore_health(*health, character);
+            restore_health(*health, character, observer);
         }
         EffectOnCharacter::Condition(condition) => {
-            apply_condition_effect(condition, character);
+            apply_condition_effect(condition, character, registry, observer);
         }
         EffectOnCharacter::GainStamina(stamina) => {
             character.stamina_current =
                 usize::min(character.stamina_current + stamina, character.stamina_max);
         }
+        EffectOnCharacter::Push(spaces) => {
+            apply_forced_movement(
+                ForcedMovement::Push,
+                *spaces,
+                character,
+                &source_character.pos,
+                board,
+                occupied,
+                registry,
+                observer,
+            );
+        }
+        EffectOnCharacter::Pull(spaces) => {
+            apply_forced_movement(
+                ForcedMovement::Pull,
+                *spaces,
+                character,
+                &source_character.pos,
+                board,
+                occupied,
+                registry,
+                observer,
+            );
+        }
+        // Replaced by Damage when the on_kill effects are applied.
+        EffectOnCharacter::OverkillDamage => {}
     }
 }
 
 pub fn apply_effect_to_character_with_same_source_character(
     effect: &EffectOnCharacter,
     character: &mut Character,
+    registry: &Registry,
+    observer: &mut dyn Observer,
 ) {
     match effect {
         EffectOnCharacter::Damage(damage) => {
             let net_damage = net_damage(*damage, character, character);
-            deal_damage(net_damage, character);
+            deal_damage(net_damage, character, None, registry, observer);
             *character
                 .turn_stats
                 .get_current_mut(TurnStat::AttackActions) += 1;
-            deal_damage(character.conditions.get(&Condition::Retaliate), character);
+            deal_damage(
+                character.conditions.get(&Condition::Retaliate),
+                character,
+                None,
+                registry,
+                observer,
+            );
         }
         EffectOnCharacter::DamageWithLifesteal(damage) => {
             let net_damage = net_damage(*damage, character, character);
-            deal_damage(net_damage, character);
+            deal_damage(net_damage, character, None, registry, observer);
             *character
                 .turn_stats
                 .get_current_mut(TurnStat::AttackActions) += 1;
-            deal_damage(character.conditions.get(&Condition::Retaliate), character);
-            restore_health(net_damage, character);
+            deal_damage(
+                character.conditions.get(&Condition::Retaliate),
+                character,
+                None,
+                registry,
+                observer,
+            );
+            restore_health(net_damage, character, observer);
         }
         EffectOnCharacter::Heal(health) => {
-            restore_health(*health, character);
+            restore_health(*health, character, observer);
         }
         EffectOnCharacter::Condition(condition) => {
-            apply_condition_effect(condition, character);
+            apply_condition_effect(condition, character, registry, observer);
         }
         EffectOnCharacter::GainStamina(stamina) => {
             character.stamina_current =
                 usize::min(character.stamina_current + stamina, character.stamina_max);
         }
+        // A character can not be moved towards or away from itself
+        EffectOnCharacter::Push(_) | EffectOnCharacter::Pull(_) => {}
+        EffectOnCharacter::OverkillDamage => {}
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ForcedMovement {
+    Push,
+    Pull,
+}
+
+// Every space moved must take the character one step further away from (push) or closer to (pull) the source.
+// Among the hexes satisfying this, the one closest to the line through the source and the character's
+// starting position is chosen. The movement stops early if every such hex is occupied or impassable.
+#[allow(clippy::too_many_arguments)]
+pub fn apply_forced_movement(
+    forced_movement: ForcedMovement,
+    spaces: usize,
+    character: &mut Character,
+    source_pos: &PosAxial,
+    board: &Board,
+    occupied: &mut HashSet<PosAxial>,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    let start = character.pos.clone();
+    for _ in 0..spaces {
+        let current_distance = distance(source_pos, &character.pos);
+        let wanted_distance = match forced_movement {
+            ForcedMovement::Push => current_distance + 1,
+            ForcedMovement::Pull => match current_distance.checked_sub(1) {
+                Some(wanted_distance) => wanted_distance,
+                None => break,
+            },
+        };
+        let next = character
+            .pos
+            .neighbors()
+            .filter(|pos| {
+                distance(source_pos, pos) == wanted_distance
+                    && board.is_standable(pos)
+                    && !occupied.contains(pos)
+            })
+            .min_by(|a, b| {
+                f64::total_cmp(
+                    &distance_from_line(a, source_pos, &start),
+                    &distance_from_line(b, source_pos, &start),
+                )
+            });
+        let Some(next) = next else {
+            break;
+        };
+        occupied.remove(&character.pos);
+        occupied.insert(next.clone());
+        move_one_space(board, character, next, registry, observer);
+        *character
+            .turn_stats
+            .get_current_mut(TurnStat::SpacesMovedForced) += 1;
+    }
+}
+
+fn distance_from_line(pos: &PosAxial, line_a: &PosAxial, line_b: &PosAxial) -> f64 {
+    let (x, y) = pos.center();
+    let (ax, ay) = line_a.center();
+    let (bx, by) = line_b.center();
+    f64::abs((bx - ax) * (y - ay) - (by - ay) * (x - ax))
+}
+
 #[rustfmt::skip]
 pub fn net_damage(
     gross_damage: usize,
@@ -160,43 +366,147 @@ pub fn net_damage(
         - character.conditions.get(&Condition::Fortified)
 }
 
-pub fn deal_damage(net_damage: usize, character: &mut Character) {
+// Returns true if the damage killed the character. A character dies when its health is brought
+// from above 0 to 0.
+pub fn deal_damage(
+    net_damage: usize,
+    character: &mut Character,
+    source: Option<CharacterId>,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> bool {
+    if net_damage == 0 || !character.is_alive() {
+        return false;
+    }
+    let health_before = character.health_current;
     character.health_current = usize::saturating_sub(character.health_current, net_damage);
-    if net_damage > 0 {
-        push_triggered_abilities(character, |x| x.damage_taken);
+    observer.on_event(&GameEvent::DamageDealt {
+        target: character.id,
+        source,
+        amount: net_damage,
+    });
+    if health_before > 0 && character.health_current == 0 {
+        kill(
+            character,
+            source,
+            net_damage - health_before,
+            registry,
+            observer,
+        );
+        return true;
     }
+    push_triggered_abilities(character, Trigger::DamageTaken, registry, observer);
+    false
 }
 
-pub fn restore_health(health: usize, character: &mut Character) {
+pub fn deal_damage_from(
+    net_damage: usize,
+    character: &mut Character,
+    source_character: &mut Character,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> bool {
+    let killed = deal_damage(
+        net_damage,
+        character,
+        Some(source_character.id),
+        registry,
+        observer,
+    );
+    if killed {
+        push_triggered_abilities(source_character, Trigger::OnKill, registry, observer);
+    }
+    killed
+}
+
+// Dead characters keep their place among the characters, but take no more turns, can't be
+// targeted and don't occupy their hex. Only their on_death abilities are left to resolve.
+pub fn kill(
+    character: &mut Character,
+    killer: Option<CharacterId>,
+    overkill: usize,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    if !character.is_alive() {
+        return;
+    }
+    character.remaining_abilities.clear();
+    push_triggered_abilities(character, Trigger::OnDeath, registry, observer);
+    character.death = Some(Death { killer, overkill });
+    observer.on_event(&GameEvent::CharacterDied {
+        character: character.id,
+        killer,
+        overkill,
+    });
+}
+
+pub fn restore_health(health: usize, character: &mut Character, observer: &mut dyn Observer) {
+    if !character.is_alive() {
+        return;
+    }
+    let health_before = character.health_current;
     character.health_current = usize::min(character.health_current + health, character.health_max);
+    if character.health_current > health_before {
+        observer.on_event(&GameEvent::HealthRestored {
+            target: character.id,
+            amount: character.health_current - health_before,
+        });
+    }
 }
 
-pub fn push_triggered_abilities<F>(character: &mut Character, f: F)
-where
-    F: Fn(TriggeredAbilities) -> Vec<Ability>,
-{
-    for ability in character
+pub fn push_triggered_abilities(
+    character: &mut Character,
+    trigger: Trigger,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    // Dead characters only resolve the on_death abilities pushed as they die.
+    if !character.is_alive() {
+        return;
+    }
+    let abilities_before = character.remaining_abilities.len();
+    for item_data in character
         .equipped_items
         .iter()
-        .map(move |item| f(item.data().triggered_abilities))
+        .filter_map(|item| registry.item(item))
     {
-        character.remaining_abilities.extend_from_slice(&ability);
+        character
+            .remaining_abilities
+            .extend_from_slice(item_data.triggered_abilities.abilities(trigger));
+    }
+    if character.remaining_abilities.len() > abilities_before {
+        observer.on_event(&GameEvent::TriggerFired {
+            character: character.id,
+            trigger,
+        });
     }
 }
 
-pub fn apply_condition_effect(condition_effect: &ConditionEffect, character: &mut Character) {
+pub fn apply_condition_effect(
+    condition_effect: &ConditionEffect,
+    character: &mut Character,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
     let mut condition_effect = condition_effect.clone();
     for modify_gained_conditions in character
         .equipped_items
         .iter()
-        .flat_map(|item| item.data().passives.modify_gained_conditions)
+        .filter_map(|item| registry.item(item))
+        .flat_map(|item_data| &item_data.passives.modify_gained_conditions)
     {
         condition_effect =
-            apply_modify_gained_conditions(&modify_gained_conditions, condition_effect.clone())
+            apply_modify_gained_conditions(modify_gained_conditions, condition_effect.clone())
     }
     character
         .conditions
         .increment(condition_effect.condition_type, condition_effect.value);
+    observer.on_event(&GameEvent::ConditionApplied {
+        target: character.id,
+        condition: condition_effect.condition_type,
+        value: condition_effect.value,
+    });
 }
 
 pub fn apply_modify_gained_conditions(
@@ -227,6 +537,7 @@ mod tests {
     use std::iter;
 
     use super::*;
+    use crate::event_log::EventLog;
 
     #[test]
     pub fn test_apply_modify_gained_conditions() {
@@ -261,6 +572,8 @@ mod tests {
                 value: 1,
             },
             &mut character,
+            &Registry::default(),
+            &mut EventLog::default(),
         );
         assert_eq!(character.conditions.get(&Condition::Disarmed), 1);
     }
@@ -279,9 +592,48 @@ mod tests {
                 ..Default::default()
             },
             &PosAxial::default(),
+            &Board::default(),
             &mut character,
             iter::empty(),
+            &Registry::default(),
+            &mut EventLog::default(),
         );
         assert_eq!(character.conditions.get(&Condition::Disarmed), 1);
     }
+
+    #[test]
+    pub fn test_apply_forced_movement() {
+        let source_pos = PosAxial::new(0, 0);
+        let mut character = Character {
+            pos: PosAxial::new(0, 1),
+            ..Default::default()
+        };
+        let mut occupied = HashSet::from([source_pos.clone(), PosAxial::new(0, 3)]);
+        apply_forced_movement(
+            ForcedMovement::Push,
+            3,
+            &mut character,
+            &source_pos,
+            &Board::default(),
+            &mut occupied,
+            &Registry::default(),
+            &mut EventLog::default(),
+        );
+        assert_eq!(distance(&source_pos, &character.pos), 4);
+        assert!(occupied.contains(&character.pos));
+        assert_eq!(character.turn_stats.get(0, &TurnStat::SpacesMovedForced), 3);
+        assert_eq!(character.turn_stats.get(0, &TurnStat::SpacesMoved), 0);
+
+        apply_forced_movement(
+            ForcedMovement::Pull,
+            10,
+            &mut character,
+            &source_pos,
+            &Board::default(),
+            &mut occupied,
+            &Registry::default(),
+            &mut EventLog::default(),
+        );
+        assert_eq!(distance(&source_pos, &character.pos), 1);
+    }
 }
@@ -0,0 +1,198 @@
+#![allow(dead_code)]
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crate::hex_grid::{PosAxial, PosOddQHex, line_of_sight};
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Terrain {
+    Wall,             // Blocks movement and line of sight.
+    Obstacle,         // Blocks movement, unless jumping.
+    DifficultTerrain, // Costs 2 movement to enter, unless jumping.
+    Hazard(usize),    // Entering deals X damage. When jumping, only the last hex counts as entered.
+}
+
+// The default board has no bounds and no terrain, i.e. an infinite empty plane.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "BoardFile", from = "BoardFile")]
+pub struct Board {
+    hexes: Option<HashSet<PosAxial>>,
+    terrain: HashMap<PosAxial, Terrain>,
+    characters_block_line_of_sight: bool,
+}
+
+// Board as written to save files. Hexes are sorted so that saving the same board always gives the
+// same file.
+#[derive(Serialize, Deserialize)]
+struct BoardFile {
+    hexes: Option<Vec<PosAxial>>,
+    terrain: Vec<(PosAxial, Terrain)>,
+    characters_block_line_of_sight: bool,
+}
+
+impl From<Board> for BoardFile {
+    fn from(board: Board) -> Self {
+        let mut terrain: Vec<_> = board.terrain.into_iter().collect();
+        terrain.sort_by(|(a, _), (b, _)| a.cmp(b));
+        Self {
+            hexes: board.hexes.map(|hexes| {
+                let mut hexes: Vec<_> = hexes.into_iter().collect();
+                hexes.sort();
+                hexes
+            }),
+            terrain,
+            characters_block_line_of_sight: board.characters_block_line_of_sight,
+        }
+    }
+}
+
+impl From<BoardFile> for Board {
+    fn from(board_file: BoardFile) -> Self {
+        Self {
+            hexes: board_file.hexes.map(|hexes| hexes.into_iter().collect()),
+            terrain: board_file.terrain.into_iter().collect(),
+            characters_block_line_of_sight: board_file.characters_block_line_of_sight,
+        }
+    }
+}
+
+impl Board {
+    pub fn from_hexes<H>(hexes: H) -> Self
+    where
+        H: IntoIterator<Item = PosAxial>,
+    {
+        Self {
+            hexes: Some(hexes.into_iter().collect()),
+            ..Default::default()
+        }
+    }
+
+    // A rectangular room in the odd-q layout used by render_hex_grid.
+    pub fn rectangle(rows: Range<isize>, cols: Range<isize>) -> Self {
+        Self::from_hexes(
+            rows.flat_map(move |r| cols.clone().map(move |q| PosOddQHex::new(r, q).to_axial())),
+        )
+    }
+
+    pub fn with_terrain(self, pos: PosAxial, terrain: Terrain) -> Self {
+        let mut this = self;
+        this.set_terrain(pos, terrain);
+        this
+    }
+
+    pub fn with_characters_blocking_line_of_sight(self, block: bool) -> Self {
+        Self {
+            characters_block_line_of_sight: block,
+            ..self
+        }
+    }
+
+    pub fn set_terrain(&mut self, pos: PosAxial, terrain: Terrain) {
+        self.terrain.insert(pos, terrain);
+    }
+
+    pub fn clear_terrain(&mut self, pos: &PosAxial) {
+        self.terrain.remove(pos);
+    }
+
+    pub fn contains(&self, pos: &PosAxial) -> bool {
+        self.hexes.as_ref().is_none_or(|hexes| hexes.contains(pos))
+    }
+
+    pub fn terrain(&self, pos: &PosAxial) -> Option<Terrain> {
+        self.terrain.get(pos).copied()
+    }
+
+    // Whether a character may move through the hex. Characters are not considered.
+    pub fn is_passable(&self, pos: &PosAxial, jump: bool) -> bool {
+        self.contains(pos)
+            && match self.terrain(pos) {
+                Some(Terrain::Wall) => false,
+                Some(Terrain::Obstacle) => jump,
+                _ => true,
+            }
+    }
+
+    // Whether a character may end its movement in the hex. Characters are not considered.
+    pub fn is_standable(&self, pos: &PosAxial) -> bool {
+        self.is_passable(pos, false)
+    }
+
+    pub fn movement_cost(&self, pos: &PosAxial, jump: bool) -> usize {
+        match self.terrain(pos) {
+            Some(Terrain::DifficultTerrain) if !jump => 2,
+            _ => 1,
+        }
+    }
+
+    pub fn blocks_line_of_sight(&self, pos: &PosAxial) -> bool {
+        !self.contains(pos) || self.terrain(pos) == Some(Terrain::Wall)
+    }
+
+    // Whether `to` can be seen from `from`, given the positions of the characters on the board.
+    pub fn has_line_of_sight(
+        &self,
+        from: &PosAxial,
+        to: &PosAxial,
+        occupied: &HashSet<PosAxial>,
+    ) -> bool {
+        line_of_sight(from, to, |pos| {
+            self.blocks_line_of_sight(pos)
+                || (self.characters_block_line_of_sight && occupied.contains(pos))
+        })
+    }
+
+    // All valid hexes, or None if the board is unbounded.
+    pub fn hexes(&self) -> Option<impl Iterator<Item = &PosAxial>> {
+        self.hexes.as_ref().map(|hexes| hexes.iter())
+    }
+
+    // The smallest odd-q rectangle containing every valid hex, or None if the board is unbounded.
+    pub fn bounds(&self) -> Option<(Range<isize>, Range<isize>)> {
+        let hexes = self.hexes.as_ref()?;
+        let odd_q: Vec<_> = hexes.iter().map(PosAxial::to_odd_q).collect();
+        let r_min = odd_q.iter().map(|pos| pos.r).min().unwrap_or_default();
+        let r_max = odd_q.iter().map(|pos| pos.r).max().unwrap_or(-1);
+        let q_min = odd_q.iter().map(|pos| pos.q).min().unwrap_or_default();
+        let q_max = odd_q.iter().map(|pos| pos.q).max().unwrap_or(-1);
+        Some((r_min..r_max + 1, q_min..q_max + 1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_board_rectangle() {
+        let board = Board::rectangle(0..3, 0..4);
+        assert_eq!(board.hexes().unwrap().count(), 12);
+        assert!(board.contains(&PosOddQHex::new(2, 3).to_axial()));
+        assert!(!board.contains(&PosOddQHex::new(3, 3).to_axial()));
+        assert_eq!(board.bounds(), Some((0..3, 0..4)));
+    }
+
+    #[test]
+    pub fn test_board_terrain() {
+        let wall = PosAxial::new(0, 1);
+        let obstacle = PosAxial::new(0, 2);
+        let difficult = PosAxial::new(0, 3);
+        let board = Board::default()
+            .with_terrain(wall.clone(), Terrain::Wall)
+            .with_terrain(obstacle.clone(), Terrain::Obstacle)
+            .with_terrain(difficult.clone(), Terrain::DifficultTerrain);
+
+        assert!(!board.is_passable(&wall, true));
+        assert!(!board.is_passable(&obstacle, false));
+        assert!(board.is_passable(&obstacle, true));
+        assert!(!board.is_standable(&obstacle));
+        assert_eq!(board.movement_cost(&difficult, false), 2);
+        assert_eq!(board.movement_cost(&difficult, true), 1);
+        assert!(board.blocks_line_of_sight(&wall));
+        assert!(!board.blocks_line_of_sight(&obstacle));
+    }
+}
@@ -0,0 +1,252 @@
+use std::{fmt::Write, path::Path};
+
+use crate::{
+    board::Terrain,
+    board_view::{BoardView, Highlight, character_name, condition_summary},
+    data_model::{Character, CharacterTeam},
+    hex_grid::PosAxial,
+};
+
+// Pixels from the center of a hex to its corners.
+const HEX_SIZE: f64 = 40.0;
+const MARGIN: f64 = 10.0;
+const HEALTH_BAR_W: f64 = 48.0;
+
+fn pixel_center(pos: &PosAxial) -> (f64, f64) {
+    let (x, y) = pos.center();
+    (x * HEX_SIZE, y * HEX_SIZE)
+}
+
+// The corners of the flat-top hex, as the points of an SVG polygon.
+fn corners(pos: &PosAxial, size: f64) -> String {
+    let (x, y) = pixel_center(pos);
+    let corners: Vec<String> = (0..6)
+        .map(|corner| {
+            let angle = f64::to_radians(60.0 * corner as f64);
+            format!(
+                "{:.1},{:.1}",
+                x + size * angle.cos(),
+                y + size * angle.sin()
+            )
+        })
+        .collect();
+    corners.join(" ")
+}
+
+fn terrain_fill(terrain: Option<Terrain>) -> &'static str {
+    match terrain {
+        Some(Terrain::Wall) => "#4a4a4a",
+        Some(Terrain::Obstacle) => "#9a9a9a",
+        Some(Terrain::DifficultTerrain) => "#d8c890",
+        Some(Terrain::Hazard(_)) => "#e8a8a0",
+        None => "#f4f1e8",
+    }
+}
+
+fn highlight_fill(highlight: Highlight) -> &'static str {
+    match highlight {
+        Highlight::LegalTarget => "#f0c000",
+        Highlight::Movement => "#30a0d0",
+        Highlight::Area => "#c040c0",
+        Highlight::Path => "#008080",
+    }
+}
+
+fn team_color(team: &CharacterTeam) -> &'static str {
+    match team {
+        CharacterTeam::Player => "#2e7d32",
+        CharacterTeam::Monster => "#b71c1c",
+    }
+}
+
+fn push_character(svg: &mut String, character: &Character) {
+    let (x, y) = pixel_center(&character.pos);
+    let color = team_color(&character.team);
+    let health = character.health_current as f64 / character.health_max.max(1) as f64;
+    let _ = writeln!(
+        svg,
+        r#"<polygon points="{}" fill="none" stroke="{}" stroke-width="3"/>"#,
+        corners(&character.pos, HEX_SIZE - 3.0),
+        color
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="9" font-weight="bold" fill="{}">{}</text>"#,
+        x,
+        y - 12.0,
+        color,
+        character_name(character)
+    );
+    let bar_x = x - HEALTH_BAR_W / 2.0;
+    let _ = writeln!(
+        svg,
+        r##"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="6" fill="#ffffff" stroke="#333333" stroke-width="0.5"/>"##,
+        bar_x,
+        y - 5.0,
+        HEALTH_BAR_W
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="6" fill="{}"/>"#,
+        bar_x,
+        y - 5.0,
+        HEALTH_BAR_W * health.min(1.0),
+        color
+    );
+    let _ = writeln!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="9">{}/{}</text>"#,
+        x,
+        y + 11.0,
+        character.health_current,
+        character.health_max
+    );
+    let conditions = condition_summary(character);
+    if !conditions.is_empty() {
+        let _ = writeln!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" font-size="8" fill="#7b1fa2">{}</text>"##,
+            x,
+            y + 22.0,
+            conditions
+        );
+    }
+}
+
+// The board as shown by the view, in the flat-top odd-q layout of render_hex_grid: terrain, the
+// highlights of the view, and the living characters.
+pub fn render_svg(view: &BoardView) -> String {
+    let board = view.board();
+    let hexes = view.shown_hexes();
+    let centers: Vec<(f64, f64)> = hexes.iter().map(pixel_center).collect();
+    let min_x = centers
+        .iter()
+        .map(|(x, _)| *x)
+        .fold(f64::INFINITY, f64::min);
+    let max_x = centers
+        .iter()
+        .map(|(x, _)| *x)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let min_y = centers
+        .iter()
+        .map(|(_, y)| *y)
+        .fold(f64::INFINITY, f64::min);
+    let max_y = centers
+        .iter()
+        .map(|(_, y)| *y)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let (left, top, width, height) = if hexes.is_empty() {
+        (0.0, 0.0, 2.0 * MARGIN, 2.0 * MARGIN)
+    } else {
+        let reach = HEX_SIZE + MARGIN;
+        (
+            min_x - reach,
+            min_y - reach,
+            max_x - min_x + 2.0 * reach,
+            max_y - min_y + 2.0 * reach,
+        )
+    };
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{:.1} {:.1} {:.1} {:.1}" width="{:.0}" height="{:.0}" font-family="sans-serif" text-anchor="middle">"#,
+        left, top, width, height, width, height
+    );
+    let _ = writeln!(
+        svg,
+        r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" fill="white"/>"#,
+        left, top, width, height
+    );
+    for pos in &hexes {
+        let (x, y) = pixel_center(pos);
+        let terrain = board.terrain(pos);
+        let _ = writeln!(
+            svg,
+            r##"<polygon points="{}" fill="{}" stroke="#333333" stroke-width="1"/>"##,
+            corners(pos, HEX_SIZE),
+            terrain_fill(terrain)
+        );
+        if let Some(highlight) = view.highlight(pos) {
+            let _ = writeln!(
+                svg,
+                r#"<polygon points="{}" fill="{}" fill-opacity="0.4"/>"#,
+                corners(pos, HEX_SIZE),
+                highlight_fill(highlight)
+            );
+        }
+        let odd_q = pos.to_odd_q();
+        let _ = writeln!(
+            svg,
+            r##"<text x="{:.1}" y="{:.1}" font-size="8" fill="#666666">{} {}</text>"##,
+            x,
+            y - 24.0,
+            odd_q.r,
+            odd_q.q
+        );
+        if let Some(Terrain::Hazard(damage)) = terrain {
+            let _ = writeln!(
+                svg,
+                r##"<text x="{:.1}" y="{:.1}" font-size="8" fill="#b71c1c">Hazard {}</text>"##,
+                x,
+                y + 31.0,
+                damage
+            );
+        }
+    }
+    for character in view.characters().iter().filter(|c| c.is_alive()) {
+        push_character(&mut svg, character);
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+// Text is drawn with the fonts installed on the system, and left out if there are none.
+#[cfg(feature = "png")]
+pub fn render_png(svg: &str) -> Result<Vec<u8>, String> {
+    use resvg::{tiny_skia, usvg};
+
+    let mut options = usvg::Options::default();
+    let fonts = options.fontdb_mut();
+    fonts.load_system_fonts();
+    // sans-serif means Arial by default, which many systems do not have
+    let sans_serif = usvg::fontdb::Query {
+        families: &[usvg::fontdb::Family::SansSerif],
+        ..Default::default()
+    };
+    if fonts.query(&sans_serif).is_none() {
+        let fallback = fonts
+            .faces()
+            .find_map(|face| face.families.first().cloned());
+        if let Some((family, _)) = fallback {
+            fonts.set_sans_serif_family(family);
+        }
+    }
+    let tree = usvg::Tree::from_str(svg, &options).map_err(|err| err.to_string())?;
+    let size = tree.size().to_int_size();
+    let mut pixmap = tiny_skia::Pixmap::new(size.width(), size.height())
+        .ok_or_else(|| "The image is empty".to_string())?;
+    resvg::render(&tree, tiny_skia::Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|err| err.to_string())
+}
+
+// Writes a PNG if the path ends in .png, and an SVG otherwise.
+pub fn export(view: &BoardView, path: &Path) -> Result<(), String> {
+    let svg = render_svg(view);
+    let is_png = path
+        .extension()
+        .is_some_and(|extension| extension.eq_ignore_ascii_case("png"));
+    let contents = if is_png {
+        #[cfg(feature = "png")]
+        {
+            render_png(&svg)?
+        }
+        #[cfg(not(feature = "png"))]
+        {
+            return Err("Exporting PNG needs the png feature".into());
+        }
+    } else {
+        svg.into_bytes()
+    };
+    std::fs::write(path, contents).map_err(|err| format!("{}: {}", path.display(), err))
+}
@@ -0,0 +1,262 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crate::{
+    board::{Board, Terrain},
+    data_model::{Action, AreaEffect, Character, CharacterTeam},
+    encounter::Encounter,
+    hex_grid::{Area, PosAxial, PosOddQHex, hexes_within, pos_in_area},
+    movement::reachable_hexes,
+    render_hex_grid::{HexContent, render_hex_grid},
+    targeting::legal_targets,
+};
+
+// The hex size the content rows are laid out for: 2 is the top row, -2 the bottom one.
+const FLAT_W: usize = 9;
+const HALF_H: usize = 3;
+const HEALTH_BAR_W: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    LegalTarget,
+    Movement,
+    Area, // Hit by the previewed area effects.
+    Path, // Part of the movement path being chosen.
+}
+
+// Shows everything on the board that matters when choosing an action: who stands where, their
+// health and conditions, terrain, and where the pending action can go.
+pub struct BoardView<'a> {
+    board: &'a Board,
+    characters: &'a [Character],
+    highlights: HashMap<PosAxial, Highlight>,
+    cursor: Option<PosAxial>,
+    colors: bool, // ANSI colors
+}
+
+impl<'a> BoardView<'a> {
+    pub fn new(board: &'a Board, characters: &'a [Character]) -> Self {
+        Self {
+            board,
+            characters,
+            highlights: HashMap::new(),
+            cursor: None,
+            colors: false,
+        }
+    }
+
+    // The board of the encounter, highlighting where its pending action can go.
+    pub fn of_encounter(encounter: &'a Encounter) -> Self {
+        let play_state = &encounter.play_state;
+        let board_view = Self::new(&encounter.board, &encounter.characters);
+        match play_state.pending_action() {
+            Some(action) => board_view.with_pending_action(action, play_state.acting()),
+            None => board_view,
+        }
+    }
+
+    pub fn with_colors(self, colors: bool) -> Self {
+        Self { colors, ..self }
+    }
+
+    // Targeted actions highlight their legal targets and movements the hexes they can reach. Actions
+    // on self preview their area around the acting character.
+    pub fn with_pending_action(mut self, action: &Action, acting: usize) -> Self {
+        let character = &self.characters[acting];
+        let others: Vec<&Character> = self
+            .characters
+            .iter()
+            .enumerate()
+            .filter(|(i, other)| *i != acting && other.is_alive())
+            .map(|(_, other)| other)
+            .collect();
+        match action {
+            Action::Targeted(action) => {
+                for target in legal_targets(action, self.board, character, &others) {
+                    self.highlights.insert(target, Highlight::LegalTarget);
+                }
+                self
+            }
+            Action::Movement(action) => {
+                let occupied: HashSet<PosAxial> =
+                    others.iter().map(|other| other.pos.clone()).collect();
+                for pos in reachable_hexes(action, self.board, character, &occupied) {
+                    self.highlights.insert(pos, Highlight::Movement);
+                }
+                self
+            }
+            Action::OnSelf(action) => {
+                let pos = character.pos.clone();
+                self.with_area_preview(&action.effects, &pos)
+            }
+        }
+    }
+
+    // Highlights every hex on the board that the effects hit when aimed at the target.
+    pub fn with_area_preview(mut self, effects: &[AreaEffect], target: &PosAxial) -> Self {
+        for area_effect in effects {
+            let radius = match &area_effect.area {
+                Area::Disk(distance_range) => distance_range.to.saturating_sub(1),
+            };
+            for pos in hexes_within(target, radius) {
+                if self.board.contains(&pos) && pos_in_area(&pos, &area_effect.area, target) {
+                    self.highlights.insert(pos, Highlight::Area);
+                }
+            }
+        }
+        self
+    }
+
+    pub fn with_path(mut self, path: &[PosAxial]) -> Self {
+        for pos in path {
+            self.highlights.insert(pos.clone(), Highlight::Path);
+        }
+        self
+    }
+
+    pub fn with_cursor(self, cursor: PosAxial) -> Self {
+        Self {
+            cursor: Some(cursor),
+            ..self
+        }
+    }
+
+    pub fn board(&self) -> &Board {
+        self.board
+    }
+
+    pub fn characters(&self) -> &[Character] {
+        self.characters
+    }
+
+    pub fn highlight(&self, pos: &PosAxial) -> Option<Highlight> {
+        self.highlights.get(pos).copied()
+    }
+
+    // Unbounded boards are shown from the corner at 0 0.
+    fn shown_bounds(&self) -> (Range<isize>, Range<isize>) {
+        self.board.bounds().unwrap_or((0..4, 0..4))
+    }
+
+    // Every hex of the board within the shown bounds.
+    pub fn shown_hexes(&self) -> Vec<PosAxial> {
+        let (rows, cols) = self.shown_bounds();
+        rows.flat_map(|r| cols.clone().map(move |q| PosOddQHex::new(r, q).to_axial()))
+            .filter(|pos| self.board.contains(pos))
+            .collect()
+    }
+
+    pub fn render(&self) -> String {
+        let (rows, cols) = self.shown_bounds();
+        render_hex_grid(self, rows, cols, FLAT_W, HALF_H)
+    }
+
+    fn paint(&self, s: String, color: &str) -> String {
+        if self.colors && !s.is_empty() {
+            format!("\x1b[{}m{}\x1b[0m", color, s)
+        } else {
+            s
+        }
+    }
+
+    fn coordinates(&self, pos: &PosOddQHex, pos_axial: &PosAxial) -> String {
+        let coordinates = format!("{} {}", pos.r, pos.q);
+        if self.cursor.as_ref() == Some(pos_axial) {
+            return self.paint(format!("[{}]", coordinates), "7");
+        }
+        match self.highlight(pos_axial) {
+            Some(Highlight::LegalTarget) => self.paint(format!(">{}<", coordinates), "1;33"),
+            Some(Highlight::Movement) => self.paint(format!("+{}+", coordinates), "36"),
+            Some(Highlight::Area) => self.paint(format!("*{}*", coordinates), "35"),
+            Some(Highlight::Path) => self.paint(format!("={}=", coordinates), "1;36"),
+            None => coordinates,
+        }
+    }
+
+    fn name(&self, character: &Character) -> String {
+        let color = match character.team {
+            CharacterTeam::Player => "1;32",
+            CharacterTeam::Monster => "1;31",
+        };
+        self.paint(character_name(character), color)
+    }
+
+    fn health_bar(&self, character: &Character) -> String {
+        let max = character.health_max.max(1);
+        let filled = (character.health_current * HEALTH_BAR_W).div_ceil(max);
+        let bar: String = (0..HEALTH_BAR_W)
+            .map(|i| if i < filled { '█' } else { '░' })
+            .collect();
+        let color = if 2 * character.health_current > max {
+            "32"
+        } else if 4 * character.health_current > max {
+            "33"
+        } else {
+            "31"
+        };
+        format!("{} {}", self.paint(bar, color), character.health_current)
+    }
+
+    fn conditions(&self, character: &Character) -> String {
+        self.paint(condition_summary(character), "35")
+    }
+
+    fn terrain(&self, pos: &PosAxial) -> String {
+        match self.board.terrain(pos) {
+            Some(Terrain::Wall) => self.paint("Wall".into(), "90"),
+            Some(Terrain::Obstacle) => self.paint("Obstacle".into(), "90"),
+            Some(Terrain::DifficultTerrain) => self.paint("Difficult".into(), "33"),
+            Some(Terrain::Hazard(damage)) => self.paint(format!("Hazard {}", damage), "31"),
+            None => Default::default(),
+        }
+    }
+}
+
+// The team and id, and the type of monsters, e.g. "M1 Skeleton".
+pub fn character_name(character: &Character) -> String {
+    let team = match character.team {
+        CharacterTeam::Player => "P",
+        CharacterTeam::Monster => "M",
+    };
+    match character.monster_type {
+        Some(monster_type) => format!("{}{} {:?}", team, character.id.0, monster_type),
+        None => format!("{}{}", team, character.id.0),
+    }
+}
+
+// The active conditions shortened to their first letters, e.g. "Poi2 Wea1".
+pub fn condition_summary(character: &Character) -> String {
+    let conditions: Vec<String> = character
+        .conditions
+        .active()
+        .iter()
+        .map(|(condition, value)| {
+            let name: String = format!("{:?}", condition).chars().take(3).collect();
+            format!("{}{}", name, value)
+        })
+        .collect();
+    conditions.join(" ")
+}
+
+impl HexContent for &BoardView<'_> {
+    fn hex_content(&self, pos: &PosOddQHex, content_row: isize) -> String {
+        let pos_axial = pos.to_axial();
+        if !self.board.contains(&pos_axial) {
+            return Default::default();
+        }
+        let character = self
+            .characters
+            .iter()
+            .find(|character| character.is_alive() && character.pos == pos_axial);
+        match (content_row, character) {
+            (2, _) => self.coordinates(pos, &pos_axial),
+            (1, Some(character)) => self.name(character),
+            (0, Some(character)) => self.health_bar(character),
+            (-1, Some(character)) => self.conditions(character),
+            (-2, _) => self.terrain(&pos_axial),
+            _ => Default::default(),
+        }
+    }
+}
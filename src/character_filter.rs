@@ -1,11 +1,12 @@
 #![allow(dead_code)]
+use serde::{Deserialize, Serialize};
 
 use crate::{
     data_model::{Character, Condition},
     hex_grid::{DistanceRange, distance, distance_within_range},
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CharacterFilter {
     IsEnemy,
     IsSelf,
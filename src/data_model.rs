@@ -1,16 +1,19 @@
 #![allow(dead_code)]
 use crate::{
     character_filter::CharacterFilter,
+    deck::Deck,
     enum_map::EnumMap,
     hex_grid::{Area, PosAxial},
     items::Item,
+    monsters::MonsterType,
     precondition::Precondition,
     turn_stats::TurnStats,
 };
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
 // All conditions stack and are decrease by 1 at the start of the character's turn
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum Condition {
     Poison,      // At the end of your turn, take X damage.
     Regen,       // At the end of your turn, heal X damage.
@@ -32,12 +35,13 @@ pub enum Condition {
     Fury, // Melee attacks target all adjacent enemies.
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ConditionEffect {
     pub condition_type: Condition,
     pub value: isize,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CardData {
     pub description: Cow<'static, str>,
     pub stamina_cost: usize,
@@ -46,59 +50,85 @@ pub struct CardData {
 
 // Abilities consists of 0 or more actions. Abilities can always be canceled.
 // Each individual action can not be canceled. Once the first is confirmed, the next must be executed.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Ability {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub precondition: Option<Precondition>,
+    #[serde(default, skip_serializing_if = "AbilityKind::is_mandatory")]
+    pub kind: AbilityKind,
     pub actions: Vec<Action>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+// Optional and choose one abilities ask the character for a choice before any of their actions.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AbilityKind {
+    #[default]
+    Mandatory,
+    Optional,                    // "You may": the ability can be declined.
+    ChooseOne(Vec<Vec<Action>>), // The chosen option is resolved after the actions of the ability.
+}
+
+impl AbilityKind {
+    pub fn is_mandatory(&self) -> bool {
+        *self == Self::Mandatory
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Action {
     OnSelf(ActionOnSelf),
     Targeted(ActionTargeted),
     Movement(ActionMovement),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionOnSelf {
     pub effects: Vec<AreaEffect>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionTargeted {
     pub reach: Reach,
     pub effects: Vec<AreaEffect>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionMovement {
     pub spaces: usize,
     pub jump: bool,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Reach {
     Melee,
     Ranged { range: usize },
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AreaEffect {
     pub area: Area,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub filter: Option<CharacterFilter>,
     pub effects: Vec<EffectOnCharacter>,
+    // Applied around every character killed by the effects above, with that character as the target.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub on_kill: Vec<AreaEffect>,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum EffectOnCharacter {
     Damage(usize),
     DamageWithLifesteal(usize),
     Heal(usize),
     Condition(ConditionEffect),
     GainStamina(usize),
+    Push(usize),    // Move the character X spaces away from the source character.
+    Pull(usize),    // Move the character X spaces towards the source character.
+    OverkillDamage, // Deal damage equal to the overkill of the kill that triggered the effect. Only meaningful in on_kill.
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Comparison {
     Equal,
     Less,
@@ -126,7 +156,7 @@ impl Comparison {
     }
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum CharacterTeam {
     Player,
     #[default]
@@ -135,8 +165,9 @@ pub enum CharacterTeam {
 
 pub type Conditions = EnumMap<Condition>;
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Character {
+    pub id: CharacterId,
     pub pos: PosAxial,
     pub team: CharacterTeam,
     pub health_current: usize,
@@ -147,38 +178,103 @@ pub struct Character {
     pub conditions: Conditions,
     pub turn_stats: TurnStats,
     pub remaining_abilities: Vec<Ability>,
+    pub death: Option<Death>,
+    pub deck: Deck,
+    #[serde(default)]
+    pub initiative: usize, // Lower acts first in every round.
+    // Monsters with a type play the revealed ability card of their type instead of their deck.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monster_type: Option<MonsterType>,
 }
 
-pub struct Player {
-    pub character: Character,
-    pub stamina_current: usize,
-    pub stamina_max: usize,
-    pub hand: Vec<CardData>,
+impl Character {
+    pub fn is_alive(&self) -> bool {
+        self.death.is_none()
+    }
 }
 
-#[derive(Default, Debug, Clone)]
+#[derive(
+    Default, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+pub struct CharacterId(pub usize);
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Death {
+    pub killer: Option<CharacterId>, // None if the character died from e.g. Poison or terrain.
+    pub overkill: usize,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct Passives {
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub actions_consume_health_instead_of_mana: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub modify_gained_conditions: Vec<ModifyGainedConditions>,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
 pub struct TriggeredAbilities {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub damage_taken: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub attack_action: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub movement_action: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub beginning_of_turn: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub end_of_turn: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub on_kill: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub on_death: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub beginning_of_round: Vec<Ability>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub end_of_round: Vec<Ability>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Trigger {
+    DamageTaken,
+    AttackAction,
+    MovementAction,
+    BeginningOfTurn,
+    EndOfTurn,
+    OnKill,
+    OnDeath,
+    BeginningOfRound,
+    EndOfRound,
+}
+
+impl TriggeredAbilities {
+    pub fn abilities(&self, trigger: Trigger) -> &[Ability] {
+        match trigger {
+            Trigger::DamageTaken => &self.damage_taken,
+            Trigger::AttackAction => &self.attack_action,
+            Trigger::MovementAction => &self.movement_action,
+            Trigger::BeginningOfTurn => &self.beginning_of_turn,
+            Trigger::EndOfTurn => &self.end_of_turn,
+            Trigger::OnKill => &self.on_kill,
+            Trigger::OnDeath => &self.on_death,
+            Trigger::BeginningOfRound => &self.beginning_of_round,
+            Trigger::EndOfRound => &self.end_of_round,
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ItemData {
     pub description: Cow<'static, str>,
+    #[serde(default)]
     pub passives: Passives,
+    #[serde(default)]
     pub triggered_abilities: TriggeredAbilities,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ModifyGainedConditions {
     pub applies_only_to: Option<Condition>,
     pub transform_into: Option<Condition>,
@@ -0,0 +1,174 @@
+#![allow(dead_code)]
+use serde::{Deserialize, Serialize};
+
+use crate::{cards::Card, rng::Rng};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DeckRules {
+    pub starting_hand_size: usize,
+    pub cards_drawn_per_turn: usize,
+    pub hand_limit: usize, // No cards are drawn while the hand is full.
+}
+
+impl Default for DeckRules {
+    fn default() -> Self {
+        Self {
+            starting_hand_size: 5,
+            cards_drawn_per_turn: 2,
+            hand_limit: 8,
+        }
+    }
+}
+
+// Played cards go to the discard pile, which is shuffled back into the draw pile when it runs
+// out. Exhausted cards are out of the encounter.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deck {
+    pub rules: DeckRules,
+    pub draw_pile: Vec<Card>, // Drawn from the back.
+    pub hand: Vec<Card>,
+    pub discard_pile: Vec<Card>,
+    pub exhausted: Vec<Card>,
+}
+
+impl Deck {
+    pub fn new<I>(cards: I) -> Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        Self {
+            draw_pile: cards.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    // Starts with the given cards in hand and nothing to draw. Mostly useful for scripted setups.
+    pub fn with_hand<I>(cards: I) -> Self
+    where
+        I: IntoIterator<Item = Card>,
+    {
+        Self {
+            hand: cards.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn with_rules(self, rules: DeckRules) -> Self {
+        Self { rules, ..self }
+    }
+
+    pub fn shuffle(&mut self, rng: &mut Rng) {
+        rng.shuffle(&mut self.draw_pile);
+    }
+
+    // Returns the drawn card, or None if the hand is full or there is nothing left to draw.
+    pub fn draw(&mut self, rng: &mut Rng) -> Option<Card> {
+        if self.hand.len() >= self.rules.hand_limit {
+            return None;
+        }
+        if self.draw_pile.is_empty() {
+            self.draw_pile.append(&mut self.discard_pile);
+            self.shuffle(rng);
+        }
+        let card = self.draw_pile.pop()?;
+        self.hand.push(card.clone());
+        Some(card)
+    }
+
+    pub fn draw_many(&mut self, count: usize, rng: &mut Rng) -> usize {
+        (0..count).take_while(|_| self.draw(rng).is_some()).count()
+    }
+
+    pub fn draw_starting_hand(&mut self, rng: &mut Rng) -> usize {
+        self.shuffle(rng);
+        self.draw_many(self.rules.starting_hand_size, rng)
+    }
+
+    pub fn draw_for_turn(&mut self, rng: &mut Rng) -> usize {
+        self.draw_many(self.rules.cards_drawn_per_turn, rng)
+    }
+
+    pub fn card_in_hand(&self, index: usize) -> Option<Card> {
+        self.hand.get(index).cloned()
+    }
+
+    pub fn discard_from_hand(&mut self, index: usize) -> Option<Card> {
+        if index >= self.hand.len() {
+            return None;
+        }
+        let card = self.hand.remove(index);
+        self.discard_pile.push(card.clone());
+        Some(card)
+    }
+
+    pub fn exhaust_from_hand(&mut self, index: usize) -> Option<Card> {
+        if index >= self.hand.len() {
+            return None;
+        }
+        let card = self.hand.remove(index);
+        self.exhausted.push(card.clone());
+        Some(card)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_deck_draw_and_reshuffle() {
+        let mut rng = Rng::new(7);
+        let mut deck = Deck::new([Card::Step, Card::Cut, Card::Strike]).with_rules(DeckRules {
+            starting_hand_size: 2,
+            cards_drawn_per_turn: 2,
+            hand_limit: 3,
+        });
+
+        assert_eq!(deck.draw_starting_hand(&mut rng), 2);
+        assert_eq!(deck.draw_pile.len(), 1);
+
+        let played = deck.discard_from_hand(0).unwrap();
+        assert_eq!(deck.discard_pile, vec![played]);
+
+        // The draw pile runs out after one card, then the discard pile is shuffled in
+        assert_eq!(deck.draw_for_turn(&mut rng), 2);
+        assert_eq!(deck.hand.len(), 3);
+        assert!(deck.discard_pile.is_empty());
+        assert!(deck.draw_pile.is_empty());
+    }
+
+    #[test]
+    pub fn test_deck_hand_limit() {
+        let mut rng = Rng::new(7);
+        let mut deck = Deck::new([Card::Step; 10]).with_rules(DeckRules {
+            starting_hand_size: 5,
+            cards_drawn_per_turn: 2,
+            hand_limit: 6,
+        });
+        deck.draw_starting_hand(&mut rng);
+        assert_eq!(deck.draw_for_turn(&mut rng), 1);
+        assert_eq!(deck.draw_for_turn(&mut rng), 0);
+        assert_eq!(deck.hand.len(), 6);
+
+        assert_eq!(deck.exhaust_from_hand(0), Some(Card::Step));
+        assert_eq!(deck.exhausted.len(), 1);
+        assert_eq!(deck.exhaust_from_hand(10), None);
+    }
+
+    #[test]
+    pub fn test_deck_shuffle_is_seeded() {
+        let cards = [
+            Card::Step,
+            Card::Dash,
+            Card::Sprint,
+            Card::Cut,
+            Card::Strike,
+            Card::LargeStrike,
+        ];
+        let mut a = Deck::new(cards.clone());
+        let mut b = Deck::new(cards);
+        a.draw_starting_hand(&mut Rng::new(3));
+        b.draw_starting_hand(&mut Rng::new(3));
+        assert_eq!(a, b);
+    }
+}
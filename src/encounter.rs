@@ -0,0 +1,77 @@
+#![allow(dead_code)]
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    board::Board,
+    data_model::{Character, CharacterId, CharacterTeam},
+    hex_grid::PosAxial,
+    play_state::PlayState,
+};
+
+// Everything needed to continue a fight.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct Encounter {
+    pub board: Board,
+    pub characters: Vec<Character>,
+    pub play_state: PlayState,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum EncounterOutcome {
+    InProgress,
+    PlayersWon,
+    MonstersWon,
+    Custom(Cow<'static, str>), // Ended by a custom objective, e.g. a scripted escape.
+}
+
+// What the players must do to win. The monsters always win when every player character is dead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Objective {
+    DefeatAllMonsters,
+    DefeatCharacter(CharacterId),
+    ReachHex(PosAxial), // Any living player character stands on the hex.
+    #[serde(skip)] // Custom objectives are code, so encounters using them can not be saved.
+    Custom {
+        description: Cow<'static, str>,
+        check: fn(&[Character]) -> Option<EncounterOutcome>,
+    },
+}
+
+// The encounter ends as soon as any of the objectives is met.
+pub fn evaluate_encounter(objectives: &[Objective], characters: &[Character]) -> EncounterOutcome {
+    if !alive_on_team(characters, CharacterTeam::Player).any(|_| true) {
+        return EncounterOutcome::MonstersWon;
+    }
+    objectives
+        .iter()
+        .find_map(|objective| evaluate_objective(objective, characters))
+        .unwrap_or(EncounterOutcome::InProgress)
+}
+
+fn evaluate_objective(objective: &Objective, characters: &[Character]) -> Option<EncounterOutcome> {
+    match objective {
+        Objective::DefeatAllMonsters => alive_on_team(characters, CharacterTeam::Monster)
+            .next()
+            .is_none()
+            .then_some(EncounterOutcome::PlayersWon),
+        Objective::DefeatCharacter(id) => characters
+            .iter()
+            .any(|character| character.id == *id && !character.is_alive())
+            .then_some(EncounterOutcome::PlayersWon),
+        Objective::ReachHex(pos) => alive_on_team(characters, CharacterTeam::Player)
+            .any(|character| character.pos == *pos)
+            .then_some(EncounterOutcome::PlayersWon),
+        Objective::Custom { check, .. } => check(characters),
+    }
+}
+
+fn alive_on_team(
+    characters: &[Character],
+    team: CharacterTeam,
+) -> impl Iterator<Item = &Character> {
+    characters
+        .iter()
+        .filter(move |character| character.team == team && character.is_alive())
+}
@@ -1,6 +1,8 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct EnumMap<K: Eq + Hash>(HashMap<K, usize>);
 
@@ -22,10 +24,35 @@ impl<K: Eq + Hash> EnumMap<K> {
         self.get(key) > 0
     }
 
-    pub fn decrement_all(&mut self) {
-        for v in self.0.values_mut() {
+    // The keys with a value above 0, in order.
+    pub fn active(&self) -> Vec<(K, usize)>
+    where
+        K: Copy + Ord,
+    {
+        let mut active: Vec<(K, usize)> = self
+            .0
+            .iter()
+            .filter(|(_, v)| **v > 0)
+            .map(|(k, v)| (*k, *v))
+            .collect();
+        active.sort();
+        active
+    }
+
+    // Returns the keys brought from 1 to 0, in order.
+    pub fn decrement_all(&mut self) -> Vec<K>
+    where
+        K: Copy + Ord,
+    {
+        let mut expired = Vec::new();
+        for (k, v) in self.0.iter_mut() {
+            if *v == 1 {
+                expired.push(*k);
+            }
             *v = v.saturating_sub(1);
         }
+        expired.sort();
+        expired
     }
 
     pub fn increment(&mut self, key: K, increment: isize) {
@@ -43,3 +70,19 @@ impl<K: Eq + Hash> Default for EnumMap<K> {
         Self(Default::default())
     }
 }
+
+// Serialized sorted, so equal maps are always written the same way.
+impl<K: Eq + Hash + Ord + Serialize> Serialize for EnumMap<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0
+            .iter()
+            .collect::<BTreeMap<_, _>>()
+            .serialize(serializer)
+    }
+}
+
+impl<'de, K: Eq + Hash + Deserialize<'de>> Deserialize<'de> for EnumMap<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        HashMap::deserialize(deserializer).map(Self)
+    }
+}
@@ -0,0 +1,209 @@
+#![allow(dead_code)]
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data_model::{Ability, ActionMovement, ActionOnSelf, ActionTargeted},
+    encounter::{Encounter, EncounterOutcome},
+    game_event::{GameEvent, Observer, RecordedInput},
+    play_state::{
+        AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn, StepError,
+        step_play_state,
+    },
+    registry::Registry,
+    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+};
+
+// Append-only record of everything that happened in an encounter.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventLog {
+    events: Vec<GameEvent>,
+}
+
+impl EventLog {
+    pub fn push(&mut self, event: GameEvent) {
+        self.events.push(event);
+    }
+
+    pub fn events(&self) -> &[GameEvent] {
+        &self.events
+    }
+
+    pub fn inputs(&self) -> impl Iterator<Item = &RecordedInput> {
+        self.events.iter().filter_map(|event| match event {
+            GameEvent::Input(input) => Some(input),
+            _ => None,
+        })
+    }
+}
+
+impl Observer for EventLog {
+    fn on_event(&mut self, event: &GameEvent) {
+        self.push(event.clone());
+    }
+}
+
+// Feeds the engine the inputs recorded in a log, in order.
+pub struct ReplayInput {
+    inputs: VecDeque<RecordedInput>,
+    replayed: usize,
+    starved: bool,  // The engine asked for an input after the recorded ones ran out.
+    diverged: bool, // The engine asked for another kind of input than the next recorded one.
+}
+
+impl ReplayInput {
+    pub fn new(log: &EventLog) -> Self {
+        Self {
+            inputs: log.inputs().cloned().collect(),
+            replayed: 0,
+            starved: false,
+            diverged: false,
+        }
+    }
+
+    fn poll<T>(&mut self, f: impl FnOnce(&RecordedInput) -> Option<T>) -> PendingInput<T> {
+        let Some(recorded) = self.inputs.front() else {
+            self.starved = true;
+            return PendingInput::Pending;
+        };
+        match f(recorded) {
+            Some(input) => {
+                self.inputs.pop_front();
+                self.replayed += 1;
+                PendingInput::Some(input)
+            }
+            None => {
+                self.diverged = true;
+                PendingInput::Pending
+            }
+        }
+    }
+
+    fn poll_cancelable<T>(
+        &mut self,
+        f: impl FnOnce(&RecordedInput) -> Option<T>,
+    ) -> PendingInput<Cancelable<T>> {
+        self.poll(|input| match input {
+            RecordedInput::Cancel => Some(Cancelable::Canceled),
+            input => f(input).map(Cancelable::Some),
+        })
+    }
+}
+
+fn on_self_input(input: &RecordedInput) -> Option<ActionInputOnSelf> {
+    match input {
+        RecordedInput::Confirm => Some(ActionInputOnSelf {}),
+        _ => None,
+    }
+}
+
+fn targeted_input(input: &RecordedInput) -> Option<ActionInputTargeted> {
+    match input {
+        RecordedInput::Targeted(input) => Some(input.clone()),
+        _ => None,
+    }
+}
+
+fn movement_input(input: &RecordedInput) -> Option<ActionInputMovement> {
+    match input {
+        RecordedInput::Movement(input) => Some(input.clone()),
+        _ => None,
+    }
+}
+
+impl Input for ReplayInput {
+    // Nothing to choose, so nothing was recorded
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Some(ActionInputOnSelf {})
+    }
+    fn poll_action_input_targeted(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        self.poll(targeted_input)
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        self.poll(movement_input)
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        _: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        self.poll_cancelable(on_self_input)
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        self.poll_cancelable(targeted_input)
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        self.poll_cancelable(movement_input)
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        self.poll(|input| match input {
+            RecordedInput::PlayCard(index) => Some(PlayCardOrEndTurn::PlayCard(*index)),
+            RecordedInput::EndTurn => Some(PlayCardOrEndTurn::EndTurn),
+            _ => None,
+        })
+    }
+    fn poll_ability_choice(&mut self, _: &Ability) -> PendingInput<AbilityChoice> {
+        self.poll(|input| match input {
+            RecordedInput::AbilityChoice(choice) => Some(*choice),
+            _ => None,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReplayError {
+    Rejected(StepError),
+    // The engine asked for another kind of input than the next recorded one, e.g. because the log
+    // was recorded from another state.
+    Diverged { replayed: usize },
+}
+
+impl From<StepError> for ReplayError {
+    fn from(err: StepError) -> Self {
+        ReplayError::Rejected(err)
+    }
+}
+
+// Replays the inputs of a log against the state it was recorded from, and returns the log of the
+// replay. Stepping stops when the engine waits for an input after the recorded ones, or the
+// encounter ends, which is also where a recording made with the same inputs stops.
+pub fn replay(
+    log: &EventLog,
+    encounter: &mut Encounter,
+    registry: &Registry,
+) -> Result<EventLog, ReplayError> {
+    const MAX_STEPS: usize = 100_000;
+    let mut input = ReplayInput::new(log);
+    let mut replay_log = EventLog::default();
+    for _ in 0..MAX_STEPS {
+        if input.starved || *encounter.play_state.outcome() != EncounterOutcome::InProgress {
+            break;
+        }
+        step_play_state(
+            &mut encounter.play_state,
+            &mut input,
+            &encounter.board,
+            &mut encounter.characters,
+            registry,
+            &mut replay_log,
+        )?;
+        if input.diverged {
+            return Err(ReplayError::Diverged {
+                replayed: input.replayed,
+            });
+        }
+    }
+    Ok(replay_log)
+}
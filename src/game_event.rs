@@ -0,0 +1,96 @@
+#![allow(dead_code)]
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cards::Card,
+    data_model::{CharacterId, Condition, Trigger},
+    encounter::EncounterOutcome,
+    hex_grid::PosAxial,
+    monsters::MonsterType,
+    play_state::AbilityChoice,
+    resolve_action::{ActionInputMovement, ActionInputTargeted},
+};
+
+// An input accepted by the engine. Replaying the recorded inputs against the same initial state
+// reproduces the same encounter.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RecordedInput {
+    PlayCard(usize),
+    EndTurn,
+    Confirm,
+    Cancel,
+    Targeted(ActionInputTargeted),
+    Movement(ActionInputMovement),
+    AbilityChoice(AbilityChoice),
+}
+
+// Everything the engine does to the characters is reported as an event, in the order it happens.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GameEvent {
+    Input(RecordedInput),
+    CardPlayed {
+        character: CharacterId,
+        card: Card,
+    },
+    DamageDealt {
+        target: CharacterId,
+        source: Option<CharacterId>, // None for e.g. Poison and terrain.
+        amount: usize,
+    },
+    HealthRestored {
+        target: CharacterId,
+        amount: usize,
+    },
+    ConditionApplied {
+        target: CharacterId,
+        condition: Condition,
+        value: isize,
+    },
+    TriggerFired {
+        character: CharacterId,
+        trigger: Trigger,
+    },
+    CharacterDied {
+        character: CharacterId,
+        killer: Option<CharacterId>,
+        overkill: usize,
+    },
+    ConditionExpired {
+        target: CharacterId,
+        condition: Condition,
+    },
+    Moved {
+        character: CharacterId,
+        from: PosAxial,
+        to: PosAxial,
+    },
+    MonsterCardRevealed {
+        monster_type: MonsterType,
+        card: usize, // Index into the ability cards of the monster type.
+    },
+    RoundBegan {
+        round: usize,
+        turn_order: Vec<CharacterId>,
+    },
+    RoundEnded {
+        round: usize,
+    },
+    TurnBegan {
+        character: CharacterId,
+    },
+    TurnEnded {
+        character: CharacterId,
+    },
+    EncounterEnded(EncounterOutcome),
+}
+
+// Passed through the engine to be told about every event as it happens, e.g. to animate a UI.
+pub trait Observer {
+    fn on_event(&mut self, event: &GameEvent);
+}
+
+impl<F: FnMut(&GameEvent)> Observer for F {
+    fn on_event(&mut self, event: &GameEvent) {
+        self(event)
+    }
+}
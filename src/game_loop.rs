@@ -0,0 +1,92 @@
+use crate::{
+    data_model::CharacterTeam,
+    encounter::{Encounter, EncounterOutcome},
+    game_event::GameEvent,
+    monster_ai::{Aggressive, MonsterAi, Strategy},
+    play_state::{Input, start_encounter, step_play_state},
+    registry::Registry,
+};
+
+// How people play an encounter: polls the inputs of the player characters, and shows what happens.
+pub trait FrontEnd: Input {
+    fn quit(&self) -> bool;
+    // Called whenever a step had events, or changed the pending action.
+    fn show(&mut self, encounter: &Encounter, registry: &Registry, events: &[GameEvent]);
+    fn show_error(&mut self, message: &str);
+    fn show_outcome(&mut self, outcome: &EncounterOutcome);
+}
+
+// Players are controlled through the front end, monsters by the AI. Runs until the encounter is
+// over, or the front end quits.
+pub fn play(encounter: &mut Encounter, registry: &Registry, front_end: &mut impl FrontEnd) {
+    play_against(
+        encounter,
+        registry,
+        front_end,
+        MonsterAi::new(Aggressive, registry),
+    );
+}
+
+// Like play, with the monsters controlled by the given AI.
+pub fn play_against<S: Strategy>(
+    encounter: &mut Encounter,
+    registry: &Registry,
+    front_end: &mut impl FrontEnd,
+    mut monster_ai: MonsterAi<S>,
+) {
+    if let Err(err) = start_encounter(&mut encounter.play_state, &mut encounter.characters) {
+        front_end.show_error(&err.to_string());
+        return;
+    }
+    front_end.show(encounter, registry, &[]);
+    let mut shown_action = None;
+    while *encounter.play_state.outcome() == EncounterOutcome::InProgress && !front_end.quit() {
+        let mut events = Vec::new();
+        let observer = &mut |event: &GameEvent| events.push(event.clone());
+        let acting = encounter.play_state.acting();
+        let Some(acting_character) = encounter.characters.get(acting) else {
+            front_end.show_error("There is no character to play, stopping");
+            return;
+        };
+        let monster_acting = acting_character.team == CharacterTeam::Monster;
+        let result = if monster_acting {
+            monster_ai.update(&encounter.board, &encounter.characters, acting);
+            step_play_state(
+                &mut encounter.play_state,
+                &mut monster_ai,
+                &encounter.board,
+                &mut encounter.characters,
+                registry,
+                observer,
+            )
+        } else {
+            step_play_state(
+                &mut encounter.play_state,
+                front_end,
+                &encounter.board,
+                &mut encounter.characters,
+                registry,
+                observer,
+            )
+        };
+        match result {
+            // The AI only chooses inputs the engine accepts, a rejected one would be asked for again
+            Err(err) if monster_acting => {
+                front_end.show_error(&format!("Monster input rejected, stopping: {}", err));
+                return;
+            }
+            Err(err) => front_end.show_error(&err.to_string()),
+            Ok(()) => {}
+        }
+        // Actions are mapped in a step of their own, and are shown before their input is polled
+        let pending_action = encounter.play_state.pending_action().cloned();
+        if events.is_empty() && pending_action == shown_action {
+            continue;
+        }
+        front_end.show(encounter, registry, &events);
+        shown_action = pending_action;
+    }
+    if *encounter.play_state.outcome() != EncounterOutcome::InProgress {
+        front_end.show_outcome(encounter.play_state.outcome());
+    }
+}
@@ -1,5 +1,7 @@
+use serde::{Deserialize, Serialize};
+
 /// A position on a hexagonal grid using axial coordinates. See https://www.redblobgames.com/grids/hexagons/.
-#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub struct PosAxial {
     pub r: isize,
     pub q: isize,
@@ -30,6 +32,27 @@ impl PosAxial {
     pub fn s(&self) -> isize {
         -self.q - self.r
     }
+    pub fn to_odd_q(&self) -> PosOddQHex {
+        let parity = self.q & 1;
+        let q = self.q;
+        let r = self.r + (self.q - parity) / 2;
+        PosOddQHex { r, q }
+    }
+    pub fn offset(&self, delta: &PosDelta) -> PosAxial {
+        PosAxial {
+            r: self.r + delta.r,
+            q: self.q + delta.q,
+        }
+    }
+    pub fn neighbors(&self) -> impl Iterator<Item = PosAxial> + '_ {
+        DIRECTIONS.iter().map(|direction| self.offset(direction))
+    }
+    /// The center of the hex in the flat-top layout used by PosOddQHex, with unit hex size.
+    pub fn center(&self) -> (f64, f64) {
+        let x = 1.5 * self.q as f64;
+        let y = f64::sqrt(3.0) * (self.r as f64 + 0.5 * self.q as f64);
+        (x, y)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -38,13 +61,23 @@ pub struct PosDelta {
     pub q: isize,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[rustfmt::skip]
+pub const DIRECTIONS: [PosDelta; 6] = [
+    PosDelta { r: 0, q: 1 },
+    PosDelta { r: -1, q: 1 },
+    PosDelta { r: -1, q: 0 },
+    PosDelta { r: 0, q: -1 },
+    PosDelta { r: 1, q: -1 },
+    PosDelta { r: 1, q: 0 },
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct DistanceRange {
     pub from: usize, // inclusive
     pub to: usize,   // not inclusive
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Area {
     Disk(DistanceRange),
 }
@@ -65,6 +98,61 @@ pub fn distance(a: &PosAxial, b: &PosAxial) -> usize {
     (isize::abs_diff(a.q, b.q) + isize::abs_diff(a.r, b.r) + isize::abs_diff(a.s(), b.s())) / 2
 }
 
+// The hexes crossed by a straight line between the centers of a and b, including a and b.
+// Lines passing exactly between two hexes are nudged to one side, given by the sign of nudge.
+pub fn hex_line(a: &PosAxial, b: &PosAxial, nudge: f64) -> Vec<PosAxial> {
+    let n = distance(a, b);
+    let epsilon = 1e-6 * nudge;
+    let (a_q, a_r) = (a.q as f64 + epsilon, a.r as f64 + epsilon);
+    let (b_q, b_r) = (b.q as f64 + epsilon, b.r as f64 + epsilon);
+    (0..=n)
+        .map(|i| {
+            let t = if n == 0 { 0.0 } else { i as f64 / n as f64 };
+            round_axial(a_q + (b_q - a_q) * t, a_r + (b_r - a_r) * t)
+        })
+        .collect()
+}
+
+fn round_axial(q: f64, r: f64) -> PosAxial {
+    let s = -q - r;
+    let (mut q_round, mut r_round, s_round) = (q.round(), r.round(), s.round());
+    let (q_diff, r_diff, s_diff) = (
+        (q_round - q).abs(),
+        (r_round - r).abs(),
+        (s_round - s).abs(),
+    );
+    if q_diff > r_diff && q_diff > s_diff {
+        q_round = -r_round - s_round;
+    } else if r_diff > s_diff {
+        r_round = -q_round - s_round;
+    }
+    PosAxial::new(r_round as isize, q_round as isize)
+}
+
+// There is line of sight if a line between the centers of a and b, nudged to either side, does not
+// cross any blocking hex. The end points themselves never block.
+pub fn line_of_sight<F>(a: &PosAxial, b: &PosAxial, blocks: F) -> bool
+where
+    F: Fn(&PosAxial) -> bool,
+{
+    [1.0, -1.0].iter().any(|nudge| {
+        let line = hex_line(a, b, *nudge);
+        line.iter()
+            .skip(1)
+            .take(line.len().saturating_sub(2))
+            .all(|pos| !blocks(pos))
+    })
+}
+
+pub fn hexes_within(center: &PosAxial, radius: usize) -> impl Iterator<Item = PosAxial> + '_ {
+    let radius = radius as isize;
+    (-radius..=radius).flat_map(move |q| {
+        let r_min = isize::max(-radius, -q - radius);
+        let r_max = isize::min(radius, -q + radius);
+        (r_min..=r_max).map(move |r| PosAxial::new(center.r + r, center.q + q))
+    })
+}
+
 pub fn distance_within_range(a: &PosAxial, b: &PosAxial, distance_range: &DistanceRange) -> bool {
     let distance = distance(a, b);
     distance_range.from <= distance && distance < distance_range.to
@@ -87,4 +175,61 @@ mod tests {
             &PosAxial::new(0, 0)
         ));
     }
+
+    #[test]
+    pub fn test_neighbors() {
+        let pos = PosAxial::new(2, -1);
+        assert_eq!(pos.neighbors().count(), 6);
+        assert!(
+            pos.neighbors()
+                .all(|neighbor| distance(&pos, &neighbor) == 1)
+        );
+    }
+
+    #[test]
+    pub fn test_hexes_within() {
+        let center = PosAxial::new(1, -2);
+        assert_eq!(hexes_within(&center, 0).count(), 1);
+        assert_eq!(hexes_within(&center, 2).count(), 19);
+        assert!(hexes_within(&center, 2).all(|pos| distance(&center, &pos) <= 2));
+    }
+
+    #[test]
+    pub fn test_hex_line() {
+        let a = PosAxial::new(0, 0);
+        let b = PosAxial::new(-3, 3);
+        let line = hex_line(&a, &b, 1.0);
+        assert_eq!(line.len(), 4);
+        assert_eq!(line.first(), Some(&a));
+        assert_eq!(line.last(), Some(&b));
+        assert!(line.windows(2).all(|w| distance(&w[0], &w[1]) == 1));
+    }
+
+    #[test]
+    pub fn test_line_of_sight() {
+        let a = PosAxial::new(0, 0);
+        let b = PosAxial::new(0, 3);
+        let wall = PosAxial::new(0, 1);
+        assert!(line_of_sight(&a, &b, |_| false));
+        assert!(!line_of_sight(&a, &b, |pos| *pos == wall));
+        assert!(line_of_sight(&a, &b, |pos| *pos == a || *pos == b));
+
+        // A line running exactly between two hexes is only blocked if both sides are
+        let c = PosAxial::new(-1, 2);
+        let left = PosAxial::new(-1, 1);
+        let right = PosAxial::new(0, 1);
+        assert!(line_of_sight(&a, &c, |pos| *pos == left));
+        assert!(line_of_sight(&a, &c, |pos| *pos == right));
+        assert!(!line_of_sight(&a, &c, |pos| *pos == left || *pos == right));
+    }
+
+    #[test]
+    pub fn test_odd_q_round_trip() {
+        for r in -3..3 {
+            for q in -3..3 {
+                let pos = PosOddQHex::new(r, q);
+                assert_eq!(pos.to_axial().to_odd_q(), pos);
+            }
+        }
+    }
 }
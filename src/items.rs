@@ -1,24 +1,23 @@
-use crate::{
-    character_filter::CharacterFilter,
-    data_model::{
-        Ability, Action, ActionOnSelf, ActionTargeted, AreaEffect, Comparison, Condition,
-        ConditionEffect, EffectOnCharacter, ItemData, ModifyGainedConditions, Passives, Reach,
-        TriggeredAbilities,
-    },
-    hex_grid::DistanceRange,
-    precondition::Precondition,
-    turn_stats::TurnStat,
-};
+#![allow(dead_code)]
+use std::{borrow::Cow, fmt};
 
-#[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub enum Item {
-    ShroudOfThePoisonFeeder, // Applied Poison is converted to Regen. Applied Regen is converted to Poison.
-    CloakOfInvisibility, // At the end of your turn, if you are not adjacent to an enemy, gain Invisible(1). After every attack action, gain Fragile(1).
-    ChestplateOfTheEnraged, // Every time damage is taken, gain Strong(1).
-    MonksRobe, // After every movement action, you may gain Disarmed(1) to apply Stunned(1) to an adjacent enemy.
-    ThorngrownVest, // At the end of your turn, if you didn't attack, gain Retaliate(2).
-    BoodboundHarness, // Your actions consume Health instead of Stamina.
+use serde::{Deserialize, Serialize};
+
+// An item is known by the name it is defined under in the Registry.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Item(Cow<'static, str>);
+
+// Built-in items referred to by name in the code. All of them are listed by Registry::item_names.
+#[rustfmt::skip]
+#[allow(non_upper_case_globals)]
+impl Item {
+    pub const ShroudOfThePoisonFeeder: Self = Self::builtin("ShroudOfThePoisonFeeder"); // Applied Poison is converted to Regen. Applied Regen is converted to Poison.
+    pub const CloakOfInvisibility: Self = Self::builtin("CloakOfInvisibility"); // At the end of your turn, if you are not adjacent to an enemy, gain Invisible(1). After every attack action, gain Fragile(1).
+    pub const ChestplateOfTheEnraged: Self = Self::builtin("ChestplateOfTheEnraged"); // Every time damage is taken, gain Strong(1).
+    pub const MonksRobe: Self = Self::builtin("MonksRobe"); // After every movement action, you may gain Disarmed(1) to apply Stunned(1) to an adjacent enemy.
+    pub const ThorngrownVest: Self = Self::builtin("ThorngrownVest"); // At the end of your turn, if you didn't attack, gain Retaliate(2).
+    pub const BoodboundHarness: Self = Self::builtin("BoodboundHarness"); // Your actions consume Health instead of Stamina.
     // VestOfRecklessness, // At the start of you turn, if you are below 30% health, gain Fortified(2)
     // HolyRobe, // At the end of you turn, if you are at full health, ?
 
@@ -27,184 +26,29 @@ pub enum Item {
     // HolySandals, // After every movement action, restore 1 health
     // SpringyShoes, // All your movement actions have Jump
     // SpikedBoots, // After every movement action, deal 1 damage to an adjacent enemy
-    StillrootLegs, // At the start of you turn, if you didn't move last turn, gain one Stamina.
+    pub const StillrootLegs: Self = Self::builtin("StillrootLegs"); // At the start of you turn, if you didn't move last turn, gain one Stamina.
+
+    pub const BloodlettersRing: Self = Self::builtin("BloodlettersRing"); // Every time you kill a character, restore 3 health.
+    pub const VolatileCore: Self = Self::builtin("VolatileCore"); // When you die, deal 3 damage to all adjacent characters.
+    pub const HourglassAmulet: Self = Self::builtin("HourglassAmulet"); // At the start of every round, restore 2 health.
 }
 
 impl Item {
-    pub fn data(self) -> ItemData {
-        match self {
-            Self::ShroudOfThePoisonFeeder => ItemData {
-                description: "Applied Poison is converted to Regen. Applied Regen is converted to Poison.".into(),
-                passives: Passives {
-                    modify_gained_conditions: vec![
-                        ModifyGainedConditions {
-                            applies_only_to: Some(Condition::Poison),
-                            transform_into: Some(Condition::Regen),
-                            additive_factor: 0,
-                            multiplicative_factor: 1.0,
-                        },
-                        ModifyGainedConditions {
-                            applies_only_to: Some(Condition::Regen),
-                            transform_into: Some(Condition::Poison),
-                            additive_factor: 0,
-                            multiplicative_factor: 1.0,
-                        },
-                    ],
-                    ..Default::default()
-                },
-                triggered_abilities: Default::default(),
-            },
-            Self::CloakOfInvisibility => ItemData {
-                description: "At the end of your turn, if you are not adjacent to an enemy, gain Invisible(1). After every attack action, gain Fragile(1).".into(),
-                passives: Default::default(),
-                triggered_abilities: TriggeredAbilities {
-                    end_of_turn: vec![Ability {
-                        precondition: Some(Precondition::FilteredCount {
-                            filter: CharacterFilter::And(vec![
-                                CharacterFilter::IsEnemy,
-                                CharacterFilter::WithinDistance(DistanceRange{from: 1, to: 2}),
-                            ]),
-                            comparison: Comparison::Equal,
-                            value: 0,
-                        }),
-                        actions: vec![Action::OnSelf(ActionOnSelf {
-                            effects: vec![AreaEffect {
-                                effects: vec![EffectOnCharacter::Condition(ConditionEffect {
-                                    condition_type: Condition::Invisible,
-                                    value: 1,
-                                })],
-                                ..Default::default()
-                            }],
-                        })],
-                    }],
-                    attack_action: vec![Ability {
-                        precondition: None,
-                        actions: vec![Action::OnSelf(ActionOnSelf {
-                            effects: vec![AreaEffect {
-                                effects: vec![EffectOnCharacter::Condition(ConditionEffect {
-                                    condition_type: Condition::Fragile,
-                                    value: 1,
-                                })],
-                                ..Default::default()
-                            }],
-                        })],
-                    }],
-                    ..Default::default()
-                },
-            },
-            Self::ChestplateOfTheEnraged => ItemData {
-                description: "Every time damage is taken, gain Strong(1).".into(),
-                passives: Default::default(),
-                triggered_abilities: TriggeredAbilities {
-                    damage_taken: vec![Ability {
-                        precondition: None,
-                        actions: vec![Action::OnSelf(ActionOnSelf {
-                            effects: vec![AreaEffect {
-                                effects: vec![EffectOnCharacter::Condition(ConditionEffect {
-                                    condition_type: Condition::Strong,
-                                    value: 1,
-                                })],
-                                ..Default::default()
-                            }],
-                        })],
-                    }],
-                    ..Default::default()
-                },
-            },
-            Self::StillrootLegs => ItemData {
-                description: "At the start of you turn, if you didn't move last turn, gain one Stamina.".into(),
-                passives: Default::default(),
-                triggered_abilities: TriggeredAbilities {
-                    beginning_of_turn: vec![Ability {
-                        precondition: Some(Precondition::TurnStat {
-                            turn_index_relative: 1,
-                            stat: TurnStat::SpacesMoved,
-                            comparison: Comparison::Equal,
-                            value: 0,
-                        }),
-                        actions: vec![Action::OnSelf(ActionOnSelf {
-                            effects: vec![AreaEffect {
-                                effects: vec![EffectOnCharacter::GainStamina(1)],
-                                ..Default::default()
-                            }],
-                        })],
-                    }],
-                    ..Default::default()
-                },
-            },
-            Self::MonksRobe => ItemData {
-                description: "After every movement action, you may gain Disarmed(1) to apply Stunned(1) to an adjacent enemy.".into(),
-                passives: Default::default(),
-                triggered_abilities: TriggeredAbilities {
-                    movement_action: vec![
-                        Ability {
-                            precondition: None,
-                            actions: vec![
-                                Action::OnSelf(ActionOnSelf {
-                                    effects: vec![AreaEffect {
-                                        effects: vec![EffectOnCharacter::Condition(
-                                            ConditionEffect {
-                                                condition_type: Condition::Disarmed,
-                                                value: 1,
-                                            },
-                                        )],
-                                        ..Default::default()
-                                    }],
-                                }),
-                                Action::Targeted(ActionTargeted {
-                                    reach: Reach::Melee,
-                                    effects: vec![AreaEffect {
-                                        effects: vec![EffectOnCharacter::Condition(
-                                            ConditionEffect {
-                                                condition_type: Condition::Stunned,
-                                                value: 1,
-                                            },
-                                        )],
-                                        ..Default::default()
-                                    }],
-                                }),
-                            ],
-                        },
-                        Ability {
-                            precondition: None,
-                            actions: vec![],
-                        },
-                    ],
-                    ..Default::default()
-                },
-            },
-            Self::ThorngrownVest => ItemData {
-                description: "At the end of your turn, if you didn't attack, gain Retaliate(2).".into(),
-                passives: Default::default(),
-                triggered_abilities: TriggeredAbilities {
-                    end_of_turn: vec![Ability {
-                        precondition: Some(Precondition::TurnStat {
-                            turn_index_relative: 1, // The engine processes turn stats before end of turn abilities, so we refer to last round
-                            stat: TurnStat::AttackActions,
-                            comparison: Comparison::Equal,
-                            value: 0,
-                        }),
-                        actions: vec![Action::OnSelf(ActionOnSelf {
-                            effects: vec![AreaEffect {
-                                effects: vec![EffectOnCharacter::Condition(ConditionEffect {
-                                    condition_type: Condition::Retaliate,
-                                    value: 2,
-                                })],
-                                ..Default::default()
-                            }],
-                        })],
-                    }],
-                    ..Default::default()
-                },
-            },
-            Self::BoodboundHarness => ItemData {
-                description: "Your actions consume Health instead of Stamina.".into(),
-                passives: Passives {
-                    actions_consume_health_instead_of_mana: true,
-                    ..Default::default()
-                },
-                triggered_abilities: Default::default(),
-            },
-        }
+    pub const fn builtin(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+
+    pub fn new(name: impl Into<String>) -> Self {
+        Self(Cow::Owned(name.into()))
+    }
+
+    pub fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for Item {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
     }
 }
@@ -1,32 +1,77 @@
 use clap::Parser;
 
+use std::{fmt::Debug, path::PathBuf};
+
 use crate::{
-    data_model::{ActionMovement, ActionOnSelf, ActionTargeted, Character},
+    board::Board,
+    board_view::BoardView,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam},
+    deck::Deck,
+    encounter::Encounter,
+    game_loop::play,
     hex_grid::PosOddQHex,
-    play_state::{Cancelable, Input, PendingInput, PlayCardOrEndTurn},
-    render_hex_grid::HexContent,
-    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+    items::Item,
+    monster_ai::Policy,
+    monsters::MonsterType,
+    play_state::PlayState,
+    registry::Registry,
+    simulate::{SimulationConfig, simulate},
+    stdin_input::{StdinInput, export_and_report, print_encounter},
+    tui::Tui,
 };
 mod apply_area_effects;
+mod board;
+mod board_svg;
+mod board_view;
 mod cards;
 mod character_filter;
 mod data_model;
+mod deck;
+mod encounter;
 mod enum_map;
+mod event_log;
+mod game_event;
+mod game_loop;
 mod hex_grid;
 mod items;
+mod monster_ai;
+mod monsters;
 mod movement;
 mod play;
 mod play_state;
 mod pop_ability;
 mod precondition;
+mod registry;
 mod render_hex_grid;
 mod resolve_action;
+mod rng;
+mod save;
+mod simulate;
 mod single_out;
+mod stdin_input;
+mod targeting;
 mod test;
+mod tui;
 mod turn_stats;
 
+// Shared by every command line parsed from stdin, none of which starts with a binary name.
 const COMMAND_NAME: &str = "";
 
+// Played by everyone who does not bring their own deck.
+const STARTER_DECK: [Card; 10] = [
+    Card::Step,
+    Card::Step,
+    Card::Dash,
+    Card::Cut,
+    Card::Cut,
+    Card::Strike,
+    Card::Strike,
+    Card::SteadyShot,
+    Card::RainOfArrows,
+    Card::Shove,
+];
+
 #[derive(clap_derive::Parser, Debug)]
 #[command(name = COMMAND_NAME)]
 struct Cli {
@@ -34,111 +79,278 @@ struct Cli {
     command: Command,
 }
 
+// Sets up the encounter, which is then played through StdinInput or the Tui.
 #[derive(clap_derive::Subcommand, Debug)]
 enum Command {
-    AddCharacter { r: isize, q: isize },
+    AddPlayer {
+        r: isize,
+        q: isize,
+        #[arg(long = "item")]
+        items: Vec<String>, // Looked up in the registry
+        #[arg(long = "card")]
+        cards: Vec<String>, // The starter deck if empty
+    },
+    AddMonster {
+        r: isize,
+        q: isize,
+        #[arg(value_parser = parse_monster_type)]
+        monster_type: MonsterType,
+        #[arg(long = "item")]
+        items: Vec<String>, // Looked up in the registry
+    },
+    MonsterLevel {
+        level: usize,
+    },
+    // Adds the cards and items of a RON file to the built-in ones, replacing those with the same name
+    LoadDefinitions {
+        path: PathBuf,
+    },
+    Export {
+        path: PathBuf, // SVG, or PNG if it ends in .png
+    },
+    // Plays the encounter as set up so far many times with the AI on both sides, and reports how it went
+    Simulate {
+        #[arg(long, default_value_t = 100)]
+        encounters: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[arg(long, value_parser = parse_policy, default_value = "Aggressive")]
+        player_policy: Policy,
+        #[arg(long, value_parser = parse_policy, default_value = "Aggressive")]
+        monster_policy: Policy,
+        #[arg(long, default_value_t = 50)]
+        max_rounds: usize,
+    },
+    Start {
+        #[arg(long)]
+        tui: bool, // Play full-screen instead of line by line
+    },
 }
 
-struct DisplayCharacters<'a> {
-    characters: &'a [Character],
+// Names are written like in the code, but case is ignored.
+fn parse_named<T: Copy + Debug>(all: &[T], name: &str) -> Result<T, String> {
+    all.iter()
+        .copied()
+        .find(|value| format!("{:?}", value).eq_ignore_ascii_case(name))
+        .ok_or_else(|| format!("Unknown name {}, expected one of {:?}", name, all))
 }
 
-impl HexContent for &DisplayCharacters<'_> {
-    fn hex_content(&self, pos: &PosOddQHex, content_row: isize) -> String {
-        let character = self
-            .characters
-            .iter()
-            .find(|character| character.pos == pos.to_axial());
-        match (content_row, character) {
-            (2, _) => format!("{} {}      ", pos.r, pos.q),
-            (0, Some(character)) => {
-                format!("{}/{}", character.health_current, character.health_max)
-            }
-            (-1, Some(_character)) => "C".into(),
-            _ => Default::default(),
-        }
-    }
+// Like parse_named, for the cards and items defined in the registry.
+fn parse_registered<T>(names: &[&str], new: impl Fn(String) -> T, name: &str) -> Result<T, String> {
+    names
+        .iter()
+        .find(|registered| registered.eq_ignore_ascii_case(name))
+        .map(|registered| new(registered.to_string()))
+        .ok_or_else(|| format!("Unknown name {}, expected one of {:?}", name, names))
 }
 
-fn main() -> Result<(), std::io::Error> {
-    let mut characters = Vec::<Character>::new();
+fn parse_items(registry: &Registry, names: &[String]) -> Result<Vec<Item>, String> {
+    names
+        .iter()
+        .map(|name| parse_registered(&registry.item_names(), Item::new, name))
+        .collect()
+}
+
+fn parse_cards(registry: &Registry, names: &[String]) -> Result<Vec<Card>, String> {
+    names
+        .iter()
+        .map(|name| parse_registered(&registry.card_names(), Card::new, name))
+        .collect()
+}
+
+fn parse_monster_type(name: &str) -> Result<MonsterType, String> {
+    parse_named(&MonsterType::ALL, name)
+}
+
+fn parse_policy(name: &str) -> Result<Policy, String> {
+    parse_named(&Policy::ALL, name)
+}
+
+// Returns None once stdin is closed.
+fn read_command() -> Result<Option<Command>, std::io::Error> {
     loop {
-        println!(
-            "{}",
-            render_hex_grid::render_hex_grid(
-                &DisplayCharacters {
-                    characters: &characters
-                },
-                0..4,
-                0..4,
-                9,
-                3
-            )
-        );
         let mut line = String::new();
-        std::io::stdin().read_line(&mut line)?;
+        if std::io::stdin().read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
         match Cli::try_parse_from(std::iter::once(COMMAND_NAME).chain(line.split_whitespace())) {
-            Ok(cli) => match cli.command {
-                Command::AddCharacter { r, q } => {
-                    characters.push(Character {
-                        pos: PosOddQHex { r, q }.to_axial(),
-                        ..Default::default()
-                    });
+            Ok(cli) => return Ok(Some(cli.command)),
+            Err(err) => println!("{}", err),
+        }
+    }
+}
+
+fn add_character(encounter: &mut Encounter, r: isize, q: isize, character: Character) {
+    let pos = PosOddQHex { r, q }.to_axial();
+    if !encounter.board.is_standable(&pos) {
+        println!("Can not place a character outside the board or on terrain");
+    } else if encounter
+        .characters
+        .iter()
+        .any(|character| character.is_alive() && character.pos == pos)
+    {
+        println!("Hex is already occupied");
+    } else {
+        encounter.characters.push(Character { pos, ..character });
+    }
+}
+
+fn main() -> Result<(), std::io::Error> {
+    let mut encounter = Encounter {
+        board: Board::rectangle(0..6, 0..6),
+        ..Default::default()
+    };
+    let mut registry = Registry::builtin();
+    print_encounter(&encounter, &registry);
+    while let Some(command) = read_command()? {
+        let id = CharacterId(encounter.characters.len());
+        match command {
+            Command::AddPlayer { r, q, items, cards } => {
+                let (items, cards) = match (
+                    parse_items(&registry, &items),
+                    parse_cards(&registry, &cards),
+                ) {
+                    (Ok(items), Ok(cards)) => (items, cards),
+                    (Err(err), _) | (_, Err(err)) => {
+                        println!("{}", err);
+                        continue;
+                    }
+                };
+                let cards = if cards.is_empty() {
+                    STARTER_DECK.to_vec()
+                } else {
+                    cards
+                };
+                let character = Character {
+                    id,
+                    team: CharacterTeam::Player,
+                    health_current: 60,
+                    health_max: 60,
+                    stamina_current: 10,
+                    stamina_max: 10,
+                    equipped_items: items,
+                    deck: Deck::new(cards),
+                    ..Default::default()
+                };
+                add_character(&mut encounter, r, q, character);
+            }
+            Command::AddMonster {
+                r,
+                q,
+                monster_type,
+                items,
+            } => {
+                let items = match parse_items(&registry, &items) {
+                    Ok(items) => items,
+                    Err(err) => {
+                        println!("{}", err);
+                        continue;
+                    }
+                };
+                let level = encounter.play_state.monster_level();
+                let character = Character {
+                    equipped_items: items,
+                    ..monster_type.spawn(id, Default::default(), level)
+                };
+                add_character(&mut encounter, r, q, character);
+            }
+            Command::MonsterLevel { level } => {
+                if encounter
+                    .characters
+                    .iter()
+                    .any(|c| c.monster_type.is_some())
+                {
+                    println!("The monster level must be set before any monster is added");
+                } else {
+                    encounter.play_state = PlayState::default().with_monster_level(level);
                 }
+            }
+            Command::LoadDefinitions { path } => match registry.load_file(&path) {
+                Ok(()) => println!("Loaded {}", path.display()),
+                Err(err) => println!("{}", err),
             },
-            Err(err) => {
-                println!("{}", err)
+            Command::Export { path } => {
+                export_and_report(&BoardView::of_encounter(&encounter), &path);
+            }
+            Command::Simulate {
+                encounters,
+                seed,
+                player_policy,
+                monster_policy,
+                max_rounds,
+            } => {
+                let config = SimulationConfig {
+                    encounters,
+                    seed,
+                    player_policy,
+                    monster_policy,
+                    max_rounds,
+                };
+                match simulate(&encounter, &config, &registry) {
+                    Ok(report) => println!("{}", report),
+                    Err(err) => println!("{}", err),
+                }
+                continue;
+            }
+            Command::Start { tui } => {
+                if tui {
+                    play(&mut encounter, &registry, &mut Tui::new()?);
+                } else {
+                    play(&mut encounter, &registry, &mut StdinInput::default());
+                }
+                return Ok(());
             }
         }
+        print_encounter(&encounter, &registry);
     }
+    Ok(())
 }
 
-struct InputDummy {}
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-impl Input for InputDummy {
-    fn poll_action_input_on_self(
-        &mut self,
-        action: &ActionOnSelf,
-    ) -> PendingInput<ActionInputOnSelf> {
-        let _ = action;
-        PendingInput::Pending
-    }
-    fn poll_action_input_targeted(
-        &mut self,
-        action: &ActionTargeted,
-    ) -> PendingInput<ActionInputTargeted> {
-        let _ = action;
-        PendingInput::Pending
-    }
-    fn poll_action_input_movement(
-        &mut self,
-        action: &ActionMovement,
-    ) -> PendingInput<ActionInputMovement> {
-        let _ = action;
-        PendingInput::Pending
-    }
-    fn poll_action_input_on_self_cancelable(
-        &mut self,
-        action: &ActionOnSelf,
-    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
-        let _ = action;
-        PendingInput::Pending
-    }
-    fn poll_action_input_targeted_cancelable(
-        &mut self,
-        action: &ActionTargeted,
-    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
-        let _ = action;
-        PendingInput::Pending
+    #[test]
+    pub fn test_parse_named() {
+        assert_eq!(parse_policy("ignorant"), Ok(Policy::Ignorant));
+        assert_eq!(parse_policy("AGGRESSIVE"), Ok(Policy::Aggressive));
+        assert_eq!(parse_monster_type("Skeleton"), Ok(MonsterType::Skeleton));
+        assert!(parse_policy("Cowardly").is_err());
+        assert!(parse_policy("").is_err());
     }
-    fn poll_action_input_movement_cancelable(
-        &mut self,
-        action: &ActionMovement,
-    ) -> PendingInput<Cancelable<ActionInputMovement>> {
-        let _ = action;
-        PendingInput::Pending
+
+    #[test]
+    pub fn test_parse_registered() {
+        let registry = Registry::builtin();
+        assert_eq!(
+            parse_cards(&registry, &["cut".to_string(), "SteadyShot".to_string()]),
+            Ok(vec![Card::Cut, Card::SteadyShot])
+        );
+        assert_eq!(parse_items(&registry, &[]), Ok(Vec::new()));
+        assert!(parse_items(&registry, &["Cut".to_string()]).is_err());
     }
-    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
-        PendingInput::Pending
+
+    #[test]
+    pub fn test_add_monster_with_items() {
+        let Ok(Cli {
+            command: Command::AddMonster { items, .. },
+        }) = Cli::try_parse_from([
+            COMMAND_NAME,
+            "add-monster",
+            "1",
+            "2",
+            "skeleton",
+            "--item",
+            "VolatileCore",
+            "--item",
+            "monksrobe",
+        ])
+        else {
+            panic!("Expected an add-monster command");
+        };
+        assert_eq!(
+            parse_items(&Registry::builtin(), &items),
+            Ok(vec![Item::VolatileCore, Item::MonksRobe])
+        );
     }
 }
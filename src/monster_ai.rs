@@ -0,0 +1,360 @@
+#![allow(dead_code)]
+use std::{
+    cmp::Reverse,
+    collections::{BTreeSet, HashMap, HashSet},
+};
+
+use crate::{
+    board::Board,
+    data_model::{
+        Ability, AbilityKind, Action, ActionMovement, ActionOnSelf, ActionTargeted, CardData,
+        Character,
+    },
+    hex_grid::{PosAxial, pos_in_area},
+    movement::check_movement,
+    play::can_afford,
+    play_state::{AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn},
+    registry::Registry,
+    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+    targeting::legal_targets,
+};
+
+// What the AI knows when it is asked for an input: a snapshot of the encounter, the index of the
+// character it chooses for, and what the cards and items do.
+#[derive(Default, Debug, Clone)]
+pub struct View {
+    pub board: Board,
+    pub characters: Vec<Character>,
+    pub acting: usize,
+    pub registry: Registry,
+}
+
+impl View {
+    pub fn character(&self) -> &Character {
+        &self.characters[self.acting]
+    }
+
+    // The cards in the hand of the acting character that it can afford, with their index.
+    pub fn playable_cards(&self) -> Vec<(usize, &CardData)> {
+        let character = self.character();
+        character
+            .deck
+            .hand
+            .iter()
+            .enumerate()
+            .filter_map(|(index, card)| Some((index, self.registry.card(card)?)))
+            .filter(|(_, card_data)| can_afford(character, card_data, &self.registry))
+            .collect()
+    }
+
+    // Every living character except the acting one.
+    pub fn others(&self) -> Vec<&Character> {
+        self.characters
+            .iter()
+            .enumerate()
+            .filter(|(index, character)| *index != self.acting && character.is_alive())
+            .map(|(_, character)| character)
+            .collect()
+    }
+
+    pub fn enemies(&self) -> impl Iterator<Item = &Character> {
+        let team = self.character().team.clone();
+        self.characters
+            .iter()
+            .filter(move |character| character.team != team && character.is_alive())
+    }
+}
+
+// A monster personality. Only the card choice must be implemented; the other choices default to
+// walking towards the nearest enemy and hitting as many enemies as possible.
+pub trait Strategy {
+    // None ends the turn.
+    fn choose_card(&mut self, view: &View) -> Option<usize>;
+
+    fn choose_path(&mut self, action: &ActionMovement, view: &View) -> Vec<PosAxial> {
+        path_towards_nearest_enemy(action, view)
+    }
+
+    // None cancels the action when it is cancelable.
+    fn choose_target(&mut self, action: &ActionTargeted, view: &View) -> Option<PosAxial> {
+        best_target(action, view)
+    }
+
+    fn use_on_self(&mut self, _action: &ActionOnSelf, _view: &View) -> bool {
+        true
+    }
+
+    // Optional abilities are used, and the first option is chosen.
+    fn choose_ability(&mut self, ability: &Ability, _view: &View) -> AbilityChoice {
+        match ability.kind {
+            AbilityKind::ChooseOne(_) => AbilityChoice::Choose(0),
+            _ => AbilityChoice::Use,
+        }
+    }
+}
+
+// Attacks whenever an enemy is in reach, otherwise closes in, otherwise uses whatever else it has.
+#[derive(Default, Debug, Clone)]
+pub struct Aggressive;
+
+impl Strategy for Aggressive {
+    fn choose_card(&mut self, view: &View) -> Option<usize> {
+        let playable = view.playable_cards();
+        let first_action = |card_data: &CardData| {
+            card_data
+                .abilities
+                .first()
+                .and_then(|ability| ability.actions.first().cloned())
+        };
+        let attack = playable.iter().find(|(_, card)| match first_action(card) {
+            Some(Action::Targeted(action)) => best_target(&action, view).is_some(),
+            _ => false,
+        });
+        let movement = playable.iter().find(|(_, card)| match first_action(card) {
+            Some(Action::Movement(action)) => !path_towards_nearest_enemy(&action, view).is_empty(),
+            _ => false,
+        });
+        let on_self = playable
+            .iter()
+            .find(|(_, card)| matches!(first_action(card), Some(Action::OnSelf(_))));
+        attack.or(movement).or(on_self).map(|(index, _)| *index)
+    }
+}
+
+// Plays the first card it can afford, whatever it does. A baseline for balancing.
+#[derive(Default, Debug, Clone)]
+pub struct Ignorant;
+
+impl Strategy for Ignorant {
+    fn choose_card(&mut self, view: &View) -> Option<usize> {
+        view.playable_cards().first().map(|(index, _)| *index)
+    }
+}
+
+// A strategy that is chosen at runtime, e.g. from the command line.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Policy {
+    #[default]
+    Aggressive,
+    Ignorant,
+}
+
+impl Policy {
+    pub const ALL: [Self; 2] = [Self::Aggressive, Self::Ignorant];
+}
+
+impl Strategy for Policy {
+    fn choose_card(&mut self, view: &View) -> Option<usize> {
+        match self {
+            Policy::Aggressive => Aggressive.choose_card(view),
+            Policy::Ignorant => Ignorant.choose_card(view),
+        }
+    }
+}
+
+// Drives the characters of one team. The view must be updated before every step, see
+// PlayState::acting.
+pub struct MonsterAi<S: Strategy> {
+    strategy: S,
+    view: View,
+}
+
+impl<S: Strategy> MonsterAi<S> {
+    pub fn new(strategy: S, registry: &Registry) -> Self {
+        Self {
+            strategy,
+            view: View {
+                registry: registry.clone(),
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn update(&mut self, board: &Board, characters: &[Character], acting: usize) {
+        self.view.board = board.clone();
+        self.view.characters = characters.to_vec();
+        self.view.acting = acting;
+    }
+
+    fn choose_target(&mut self, action: &ActionTargeted) -> Option<ActionInputTargeted> {
+        self.strategy
+            .choose_target(action, &self.view)
+            .map(|target| ActionInputTargeted { target })
+    }
+}
+
+impl<S: Strategy> Input for MonsterAi<S> {
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Some(ActionInputOnSelf {})
+    }
+    // The action can not be skipped, so any legal target is better than none.
+    fn poll_action_input_targeted(
+        &mut self,
+        action: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        let target = self.choose_target(action).or_else(|| {
+            legal_targets(
+                action,
+                &self.view.board,
+                self.view.character(),
+                &self.view.others(),
+            )
+            .into_iter()
+            .next()
+            .map(|target| ActionInputTargeted { target })
+        });
+        match target {
+            Some(target) => PendingInput::Some(target),
+            None => PendingInput::Pending,
+        }
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        action: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        PendingInput::Some(ActionInputMovement {
+            path: self.strategy.choose_path(action, &self.view),
+        })
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        action: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        if self.strategy.use_on_self(action, &self.view) {
+            PendingInput::Some(Cancelable::Some(ActionInputOnSelf {}))
+        } else {
+            PendingInput::Some(Cancelable::Canceled)
+        }
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        action: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        match self.choose_target(action) {
+            Some(target) => PendingInput::Some(Cancelable::Some(target)),
+            None => PendingInput::Some(Cancelable::Canceled),
+        }
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        action: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        PendingInput::Some(Cancelable::Some(ActionInputMovement {
+            path: self.strategy.choose_path(action, &self.view),
+        }))
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        match self.strategy.choose_card(&self.view) {
+            Some(index) => PendingInput::Some(PlayCardOrEndTurn::PlayCard(index)),
+            None => PendingInput::Some(PlayCardOrEndTurn::EndTurn),
+        }
+    }
+    fn poll_ability_choice(&mut self, ability: &Ability) -> PendingInput<AbilityChoice> {
+        PendingInput::Some(self.strategy.choose_ability(ability, &self.view))
+    }
+}
+
+// The legal target hitting the most enemies, minus allies, of the acting character. Ties go to the
+// target hitting the enemy with the least health. None if no target hits more enemies than allies.
+pub fn best_target(action: &ActionTargeted, view: &View) -> Option<PosAxial> {
+    let character = view.character();
+    let others = view.others();
+    let score = |target: &PosAxial| {
+        let mut score = 0isize;
+        let mut lowest_health = usize::MAX;
+        for area_effect in &action.effects {
+            for other in others.iter().copied().chain([character]) {
+                if !pos_in_area(&other.pos, &area_effect.area, target) {
+                    continue;
+                }
+                if other.team == character.team {
+                    score -= 1;
+                } else {
+                    score += 1;
+                    lowest_health = lowest_health.min(other.health_current);
+                }
+            }
+        }
+        (score, Reverse(lowest_health))
+    };
+    legal_targets(action, &view.board, character, &others)
+        .into_iter()
+        .map(|target| (score(&target), target))
+        .filter(|((score, _), _)| *score > 0)
+        .max_by(|(a, _), (b, _)| a.cmp(b))
+        .map(|(_, target)| target)
+}
+
+// The longest part of a shortest path to a hex next to the nearest enemy that the action allows.
+// Empty if the character is already next to an enemy or no enemy can be reached.
+pub fn path_towards_nearest_enemy(action: &ActionMovement, view: &View) -> Vec<PosAxial> {
+    // Large enough for any board that fits on a screen, and keeps the search finite on the default
+    // board, which has no edges.
+    const MAX_SEARCHED_HEXES: usize = 4096;
+
+    let character = view.character();
+    let occupied: HashSet<PosAxial> = view
+        .others()
+        .into_iter()
+        .map(|other| other.pos.clone())
+        .collect();
+    let goals: HashSet<PosAxial> = view
+        .enemies()
+        .flat_map(|enemy| enemy.pos.neighbors().collect::<Vec<_>>())
+        .filter(|pos| view.board.is_standable(pos) && !occupied.contains(pos))
+        .chain(
+            view.enemies()
+                .any(|enemy| enemy.pos.neighbors().any(|pos| pos == character.pos))
+                .then(|| character.pos.clone()),
+        )
+        .collect();
+    if goals.is_empty() {
+        return Vec::new();
+    }
+
+    // Dijkstra, with the queue ordered by cost and then position so that ties are broken the same way every time
+    let mut came_from: HashMap<PosAxial, PosAxial> = HashMap::new();
+    let mut costs: HashMap<PosAxial, usize> = HashMap::from([(character.pos.clone(), 0)]);
+    let mut queue = BTreeSet::from([(0, character.pos.clone())]);
+    let mut goal = None;
+    while let Some((cost, pos)) = queue.pop_first() {
+        if goals.contains(&pos) {
+            goal = Some(pos);
+            break;
+        }
+        if costs.len() > MAX_SEARCHED_HEXES {
+            break;
+        }
+        for next in pos.neighbors() {
+            if !view.board.is_passable(&next, action.jump)
+                || (!action.jump && occupied.contains(&next))
+            {
+                continue;
+            }
+            let next_cost = cost + view.board.movement_cost(&next, action.jump);
+            if costs.get(&next).is_none_or(|known| next_cost < *known) {
+                if let Some(known) = costs.insert(next.clone(), next_cost) {
+                    queue.remove(&(known, next.clone()));
+                }
+                came_from.insert(next.clone(), pos.clone());
+                queue.insert((next_cost, next));
+            }
+        }
+    }
+    let Some(mut pos) = goal else {
+        return Vec::new();
+    };
+    let mut path = Vec::new();
+    while let Some(previous) = came_from.get(&pos) {
+        path.push(pos);
+        pos = previous.clone();
+    }
+    path.reverse();
+
+    while !path.is_empty()
+        && check_movement(action, &view.board, character, &occupied, &path).is_err()
+    {
+        path.pop();
+    }
+    path
+}
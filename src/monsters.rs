@@ -0,0 +1,378 @@
+#![allow(dead_code)]
+use std::borrow::Cow;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    data_model::{
+        Ability, AbilityKind, Action, ActionMovement, ActionOnSelf, ActionTargeted, AreaEffect,
+        Character, CharacterId, CharacterTeam, Condition, ConditionEffect, EffectOnCharacter,
+        Reach,
+    },
+    hex_grid::PosAxial,
+    rng::Rng,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum MonsterType {
+    Skeleton,
+    SkeletonArcher,
+}
+
+// The stats of a monster type at one monster level. Movement and damage are added to every
+// movement action and attack of the type's ability cards.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonsterStats {
+    pub health: usize,
+    pub movement: usize,
+    pub damage: usize,
+}
+
+// One ability card is revealed per round for each monster type, and every monster of the type
+// performs it on its turn.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonsterAbilityCard {
+    pub name: Cow<'static, str>,
+    pub initiative: usize,
+    pub shuffle: bool, // The whole deck is shuffled after the round this card was revealed in.
+    pub abilities: Vec<Ability>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonsterTypeData {
+    pub description: Cow<'static, str>,
+    pub stats: Vec<MonsterStats>, // Indexed by monster level.
+    pub ability_cards: Vec<MonsterAbilityCard>,
+}
+
+impl MonsterType {
+    pub const ALL: [Self; 2] = [Self::Skeleton, Self::SkeletonArcher];
+
+    pub fn data(self) -> MonsterTypeData {
+        match self {
+            Self::Skeleton => MonsterTypeData {
+                description: "Slow, but hits hard up close.".into(),
+                stats: vec![
+                    MonsterStats {
+                        health: 5,
+                        movement: 2,
+                        damage: 2,
+                    },
+                    MonsterStats {
+                        health: 6,
+                        movement: 2,
+                        damage: 2,
+                    },
+                    MonsterStats {
+                        health: 7,
+                        movement: 3,
+                        damage: 3,
+                    },
+                    MonsterStats {
+                        health: 9,
+                        movement: 3,
+                        damage: 3,
+                    },
+                ],
+                ability_cards: vec![
+                    MonsterAbilityCard {
+                        name: "Shamble".into(),
+                        initiative: 64,
+                        shuffle: false,
+                        abilities: vec![monster_move(0), monster_melee_attack(0)],
+                    },
+                    MonsterAbilityCard {
+                        name: "Lunge".into(),
+                        initiative: 45,
+                        shuffle: false,
+                        abilities: vec![monster_move(1), monster_melee_attack(0)],
+                    },
+                    MonsterAbilityCard {
+                        name: "Bone Guard".into(),
+                        initiative: 20,
+                        shuffle: false,
+                        abilities: vec![
+                            monster_on_self(Condition::Fortified, 2),
+                            monster_melee_attack(0),
+                        ],
+                    },
+                    MonsterAbilityCard {
+                        name: "Rattle".into(),
+                        initiative: 81,
+                        shuffle: true,
+                        abilities: vec![monster_move(1), monster_melee_attack(1)],
+                    },
+                ],
+            },
+            Self::SkeletonArcher => MonsterTypeData {
+                description: "Keeps its distance and shoots.".into(),
+                stats: vec![
+                    MonsterStats {
+                        health: 4,
+                        movement: 1,
+                        damage: 2,
+                    },
+                    MonsterStats {
+                        health: 5,
+                        movement: 1,
+                        damage: 2,
+                    },
+                    MonsterStats {
+                        health: 6,
+                        movement: 2,
+                        damage: 2,
+                    },
+                    MonsterStats {
+                        health: 7,
+                        movement: 2,
+                        damage: 3,
+                    },
+                ],
+                ability_cards: vec![
+                    MonsterAbilityCard {
+                        name: "Volley".into(),
+                        initiative: 32,
+                        shuffle: false,
+                        abilities: vec![monster_move(0), monster_ranged_attack(3, 0, vec![])],
+                    },
+                    MonsterAbilityCard {
+                        name: "Aimed Shot".into(),
+                        initiative: 56,
+                        shuffle: false,
+                        abilities: vec![monster_ranged_attack(4, 1, vec![])],
+                    },
+                    MonsterAbilityCard {
+                        name: "Poison Arrow".into(),
+                        initiative: 16,
+                        shuffle: false,
+                        abilities: vec![monster_ranged_attack(
+                            3,
+                            0,
+                            vec![EffectOnCharacter::Condition(ConditionEffect {
+                                condition_type: Condition::Poison,
+                                value: 1,
+                            })],
+                        )],
+                    },
+                    MonsterAbilityCard {
+                        name: "Fire and Fall Back".into(),
+                        initiative: 68,
+                        shuffle: true,
+                        abilities: vec![monster_ranged_attack(3, 0, vec![]), monster_move(1)],
+                    },
+                ],
+            },
+        }
+    }
+
+    // Levels above the highest defined one use its stats.
+    pub fn stats(self, level: usize) -> MonsterStats {
+        let mut stats = self.data().stats;
+        let level = level.min(stats.len() - 1);
+        stats.swap_remove(level)
+    }
+
+    pub fn spawn(self, id: CharacterId, pos: PosAxial, level: usize) -> Character {
+        let stats = self.stats(level);
+        Character {
+            id,
+            pos,
+            team: CharacterTeam::Monster,
+            health_current: stats.health,
+            health_max: stats.health,
+            monster_type: Some(self),
+            ..Default::default()
+        }
+    }
+}
+
+// The abilities of the card with the stats added, also to the options of choose one abilities.
+pub fn monster_abilities(card: &MonsterAbilityCard, stats: &MonsterStats) -> Vec<Ability> {
+    let with_stats = |actions: &[Action]| -> Vec<Action> {
+        actions
+            .iter()
+            .map(|action| action_with_stats(action, stats))
+            .collect()
+    };
+    card.abilities
+        .iter()
+        .map(|ability| Ability {
+            actions: with_stats(&ability.actions),
+            kind: match &ability.kind {
+                AbilityKind::ChooseOne(options) => AbilityKind::ChooseOne(
+                    options.iter().map(|option| with_stats(option)).collect(),
+                ),
+                kind => kind.clone(),
+            },
+            ..ability.clone()
+        })
+        .collect()
+}
+
+fn action_with_stats(action: &Action, stats: &MonsterStats) -> Action {
+    match action {
+        Action::Movement(action) => Action::Movement(ActionMovement {
+            spaces: action.spaces + stats.movement,
+            ..action.clone()
+        }),
+        Action::Targeted(action) => Action::Targeted(ActionTargeted {
+            effects: action
+                .effects
+                .iter()
+                .map(|area_effect| AreaEffect {
+                    effects: area_effect
+                        .effects
+                        .iter()
+                        .map(|effect| match effect {
+                            EffectOnCharacter::Damage(damage) => {
+                                EffectOnCharacter::Damage(damage + stats.damage)
+                            }
+                            effect => effect.clone(),
+                        })
+                        .collect(),
+                    ..area_effect.clone()
+                })
+                .collect(),
+            ..action.clone()
+        }),
+        action => action.clone(),
+    }
+}
+
+// Cards are referred to by their index in the type's ability cards.
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MonsterAbilityDeck {
+    draw_pile: Vec<usize>, // Drawn from the back.
+    discard_pile: Vec<usize>,
+    revealed: Option<usize>,
+}
+
+impl MonsterAbilityDeck {
+    // Everything starts in the discard pile, so the first reveal shuffles the whole deck.
+    pub fn new(monster_type: MonsterType) -> Self {
+        Self {
+            discard_pile: (0..monster_type.data().ability_cards.len()).collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn revealed(&self) -> Option<usize> {
+        self.revealed
+    }
+
+    // Discards the previously revealed card and reveals the next. Returns None if the type has no
+    // ability cards.
+    pub fn reveal(&mut self, monster_type: MonsterType, rng: &mut Rng) -> Option<usize> {
+        let cards = monster_type.data().ability_cards;
+        if let Some(previous) = self.revealed.take() {
+            self.discard_pile.push(previous);
+            if cards[previous].shuffle {
+                self.discard_pile.append(&mut self.draw_pile);
+            }
+        }
+        if self.draw_pile.is_empty() {
+            self.draw_pile.append(&mut self.discard_pile);
+            rng.shuffle(&mut self.draw_pile);
+        }
+        self.revealed = self.draw_pile.pop();
+        self.revealed
+    }
+}
+
+fn monster_move(modifier: usize) -> Ability {
+    Ability {
+        precondition: None,
+        kind: AbilityKind::Mandatory,
+        actions: vec![Action::Movement(ActionMovement {
+            spaces: modifier,
+            jump: false,
+        })],
+    }
+}
+
+fn monster_melee_attack(modifier: usize) -> Ability {
+    Ability {
+        precondition: None,
+        kind: AbilityKind::Mandatory,
+        actions: vec![Action::Targeted(ActionTargeted {
+            reach: Reach::Melee,
+            effects: vec![AreaEffect {
+                effects: vec![EffectOnCharacter::Damage(modifier)],
+                ..Default::default()
+            }],
+        })],
+    }
+}
+
+fn monster_ranged_attack(
+    range: usize,
+    modifier: usize,
+    additional_effects: Vec<EffectOnCharacter>,
+) -> Ability {
+    Ability {
+        precondition: None,
+        kind: AbilityKind::Mandatory,
+        actions: vec![Action::Targeted(ActionTargeted {
+            reach: Reach::Ranged { range },
+            effects: vec![AreaEffect {
+                effects: [EffectOnCharacter::Damage(modifier)]
+                    .into_iter()
+                    .chain(additional_effects)
+                    .collect(),
+                ..Default::default()
+            }],
+        })],
+    }
+}
+
+fn monster_on_self(condition_type: Condition, value: isize) -> Ability {
+    Ability {
+        precondition: None,
+        kind: AbilityKind::Mandatory,
+        actions: vec![Action::OnSelf(ActionOnSelf {
+            effects: vec![AreaEffect {
+                effects: vec![EffectOnCharacter::Condition(ConditionEffect {
+                    condition_type,
+                    value,
+                })],
+                ..Default::default()
+            }],
+        })],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_monster_ability_deck_reveals_every_card_before_repeating() {
+        let mut deck = MonsterAbilityDeck::new(MonsterType::Skeleton);
+        let mut rng = Rng::new(7);
+        let card_count = MonsterType::Skeleton.data().ability_cards.len();
+        let mut revealed: Vec<usize> = Vec::new();
+        // Stop before the shuffle card would reshuffle the remaining cards
+        while let Some(card) = deck.reveal(MonsterType::Skeleton, &mut rng) {
+            revealed.push(card);
+            if MonsterType::Skeleton.data().ability_cards[card].shuffle {
+                break;
+            }
+        }
+        let mut sorted = revealed.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(sorted.len(), revealed.len());
+        assert!(revealed.len() <= card_count);
+    }
+
+    #[test]
+    pub fn test_monster_stats_above_highest_level() {
+        assert_eq!(
+            MonsterType::Skeleton.stats(100),
+            MonsterType::Skeleton.stats(3)
+        );
+        let skeleton = MonsterType::Skeleton.spawn(CharacterId(3), PosAxial::new(1, 1), 2);
+        assert_eq!(skeleton.health_current, 7);
+        assert_eq!(skeleton.monster_type, Some(MonsterType::Skeleton));
+    }
+}
@@ -1,20 +1,185 @@
+use std::collections::{BTreeSet, HashMap, HashSet};
+
 use crate::{
-    apply_area_effects::push_triggered_abilities,
-    data_model::Character,
+    apply_area_effects::{deal_damage, push_triggered_abilities},
+    board::{Board, Terrain},
+    data_model::{ActionMovement, Character, Condition, Trigger},
+    game_event::{GameEvent, Observer},
     hex_grid::{PosAxial, distance},
+    registry::Registry,
     turn_stats::TurnStat,
 };
 
-pub fn perform_movement_unchecked<'a, P>(character: &mut Character, path: P)
-where
-    P: Iterator<Item = &'a PosAxial>,
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MovementError {
+    Immobilized,
+    PathTooLong { spaces: usize, cost: usize },
+    StepNotAdjacent { from: PosAxial, to: PosAxial },
+    Impassable { pos: PosAxial },
+    PathBlocked { pos: PosAxial },
+    DestinationOccupied { pos: PosAxial },
+}
+
+impl std::fmt::Display for MovementError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MovementError::Immobilized => write!(f, "The character is Immobilized"),
+            MovementError::PathTooLong { spaces, cost } => {
+                write!(
+                    f,
+                    "The path costs {} spaces, but only {} are allowed",
+                    cost, spaces
+                )
+            }
+            MovementError::StepNotAdjacent { from, to } => {
+                write!(f, "{:?} is not adjacent to {:?}", to, from)
+            }
+            MovementError::Impassable { pos } => write!(f, "{:?} can not be entered", pos),
+            MovementError::PathBlocked { pos } => write!(f, "{:?} is occupied", pos),
+            MovementError::DestinationOccupied { pos } => {
+                write!(f, "The destination {:?} is occupied", pos)
+            }
+        }
+    }
+}
+
+// An empty path is always valid, and represents choosing not to move.
+// Slow and Fast are expected to already be applied to the action, see pop_ability::map_action.
+pub fn check_movement(
+    action: &ActionMovement,
+    board: &Board,
+    character: &Character,
+    occupied: &HashSet<PosAxial>,
+    path: &[PosAxial],
+) -> Result<(), MovementError> {
+    let Some(destination) = path.last() else {
+        return Ok(());
+    };
+    if character.conditions.has(&Condition::Immobilized) {
+        return Err(MovementError::Immobilized);
+    }
+    let mut from = &character.pos;
+    for to in path {
+        if distance(from, to) != 1 {
+            return Err(MovementError::StepNotAdjacent {
+                from: from.clone(),
+                to: to.clone(),
+            });
+        }
+        if !board.is_passable(to, action.jump) {
+            return Err(MovementError::Impassable { pos: to.clone() });
+        }
+        if !action.jump && to != destination && occupied.contains(to) {
+            return Err(MovementError::PathBlocked { pos: to.clone() });
+        }
+        from = to;
+    }
+    if !board.is_standable(destination) {
+        return Err(MovementError::Impassable {
+            pos: destination.clone(),
+        });
+    }
+    if occupied.contains(destination) {
+        return Err(MovementError::DestinationOccupied {
+            pos: destination.clone(),
+        });
+    }
+    let spaces = action.spaces;
+    let cost = path
+        .iter()
+        .map(|pos| board.movement_cost(pos, action.jump))
+        .sum();
+    if cost > spaces {
+        return Err(MovementError::PathTooLong { spaces, cost });
+    }
+    Ok(())
+}
+
+// Every destination of a path that passes check_movement, in sorted order. Excludes the character's
+// own hex, as not moving is always possible.
+pub fn reachable_hexes(
+    action: &ActionMovement,
+    board: &Board,
+    character: &Character,
+    occupied: &HashSet<PosAxial>,
+) -> Vec<PosAxial> {
+    if character.conditions.has(&Condition::Immobilized) {
+        return Vec::new();
+    }
+    // Dijkstra, limited to the spaces of the action
+    let mut costs: HashMap<PosAxial, usize> = HashMap::from([(character.pos.clone(), 0)]);
+    let mut queue = BTreeSet::from([(0, character.pos.clone())]);
+    while let Some((cost, pos)) = queue.pop_first() {
+        // Others can only be passed when jumping, and are never a destination
+        if pos != character.pos && !action.jump && occupied.contains(&pos) {
+            continue;
+        }
+        for next in pos.neighbors() {
+            if !board.is_passable(&next, action.jump) {
+                continue;
+            }
+            let next_cost = cost + board.movement_cost(&next, action.jump);
+            if next_cost <= action.spaces && costs.get(&next).is_none_or(|known| next_cost < *known)
+            {
+                if let Some(known) = costs.insert(next.clone(), next_cost) {
+                    queue.remove(&(known, next.clone()));
+                }
+                queue.insert((next_cost, next));
+            }
+        }
+    }
+    let mut reachable: Vec<_> = costs
+        .into_keys()
+        .filter(|pos| *pos != character.pos && board.is_standable(pos) && !occupied.contains(pos))
+        .collect();
+    reachable.sort();
+    reachable
+}
+
+pub fn perform_movement_unchecked<'a, P>(
+    board: &Board,
+    character: &mut Character,
+    path: P,
+    jump: bool,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) where
+    P: ExactSizeIterator<Item = &'a PosAxial>,
 {
-    // TODO: Check path is clear enough
-    for pos in path {
+    let path_length = path.len();
+    for (i, pos) in path.enumerate() {
         debug_assert_eq!(distance(&character.pos, pos), 1); // TODO: We could represent a path as a series of directions to eliminate this failure case
-        character.pos = pos.clone();
+        if jump && i + 1 < path_length {
+            observer.on_event(&GameEvent::Moved {
+                character: character.id,
+                from: character.pos.clone(),
+                to: pos.clone(),
+            });
+            character.pos = pos.clone();
+        } else {
+            move_one_space(board, character, pos.clone(), registry, observer);
+        }
         *character.turn_stats.get_current_mut(TurnStat::SpacesMoved) += 1;
-        // TODO: Resolve environment effects
     }
-    push_triggered_abilities(character, |x| x.movement_action);
+    push_triggered_abilities(character, Trigger::MovementAction, registry, observer);
+}
+
+// Shared by movement actions and forced movement (push/pull), so that everything that happens
+// when a character enters a hex is resolved the same way regardless of why it moved.
+pub fn move_one_space(
+    board: &Board,
+    character: &mut Character,
+    pos: PosAxial,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    observer.on_event(&GameEvent::Moved {
+        character: character.id,
+        from: character.pos.clone(),
+        to: pos.clone(),
+    });
+    character.pos = pos;
+    if let Some(Terrain::Hazard(damage)) = board.terrain(&character.pos) {
+        deal_damage(damage, character, None, registry, observer);
+    }
 }
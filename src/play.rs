@@ -1,26 +1,183 @@
 use crate::{
     apply_area_effects::{deal_damage, push_triggered_abilities, restore_health},
-    data_model::{CardData, Character, Condition},
+    data_model::{CardData, Character, Condition, Trigger},
+    game_event::{GameEvent, Observer},
+    registry::Registry,
 };
 
-pub fn play_card_unchecked(character: &mut Character, card_data: CardData) -> usize {
-    debug_assert!(character.stamina_current >= card_data.stamina_cost);
-    character.stamina_current =
-        usize::saturating_sub(character.stamina_current, card_data.stamina_cost);
-    let mut abilities = card_data.abilities;
-    let number_of_abilities_gained = abilities.len();
-    character.remaining_abilities.append(&mut abilities);
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardCost {
+    Stamina(usize),
+    Health(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayCardError {
+    NotEnoughStamina { cost: usize, available: usize },
+    NotEnoughHealth { cost: usize, available: usize },
+    CardNotInHand { index: usize },
+    UnknownCard { index: usize }, // The card in hand is not defined in the registry.
+}
+
+impl std::fmt::Display for PlayCardError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayCardError::NotEnoughStamina { cost, available } => {
+                write!(f, "Costs {} stamina, but only {} is left", cost, available)
+            }
+            PlayCardError::NotEnoughHealth { cost, available } => {
+                write!(f, "Costs {} health, but only {} is left", cost, available)
+            }
+            PlayCardError::CardNotInHand { index } => {
+                write!(f, "There is no card at position {} in the hand", index)
+            }
+            PlayCardError::UnknownCard { index } => {
+                write!(
+                    f,
+                    "The card at position {} in the hand is not defined",
+                    index
+                )
+            }
+        }
+    }
+}
+
+pub fn card_cost(character: &Character, card_data: &CardData, registry: &Registry) -> CardCost {
+    if character
+        .equipped_items
+        .iter()
+        .filter_map(|item| registry.item(item))
+        .any(|item_data| item_data.passives.actions_consume_health_instead_of_mana)
+    {
+        CardCost::Health(card_data.stamina_cost)
+    } else {
+        CardCost::Stamina(card_data.stamina_cost)
+    }
+}
+
+// A character can not pay with its last health, so paying with health never kills.
+pub fn check_can_afford(
+    character: &Character,
+    card_data: &CardData,
+    registry: &Registry,
+) -> Result<(), PlayCardError> {
+    match card_cost(character, card_data, registry) {
+        CardCost::Stamina(cost) if cost > character.stamina_current => {
+            Err(PlayCardError::NotEnoughStamina {
+                cost,
+                available: character.stamina_current,
+            })
+        }
+        CardCost::Health(cost) if cost > 0 && cost >= character.health_current => {
+            Err(PlayCardError::NotEnoughHealth {
+                cost,
+                available: character.health_current,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn can_afford(character: &Character, card_data: &CardData, registry: &Registry) -> bool {
+    check_can_afford(character, card_data, registry).is_ok()
+}
+
+pub fn play_card(
+    character: &mut Character,
+    card_data: CardData,
+    registry: &Registry,
+) -> Result<usize, PlayCardError> {
+    check_can_afford(character, &card_data, registry)?;
+    Ok(play_card_unchecked(character, card_data, registry))
+}
+
+// Plays the card at the given position in the character's hand, and discards it.
+pub fn play_card_from_hand(
+    character: &mut Character,
+    index: usize,
+    registry: &Registry,
+) -> Result<usize, PlayCardError> {
+    let card = character
+        .deck
+        .card_in_hand(index)
+        .ok_or(PlayCardError::CardNotInHand { index })?;
+    let card_data = registry
+        .card(&card)
+        .ok_or(PlayCardError::UnknownCard { index })?;
+    let abilities_gained = play_card(character, card_data.clone(), registry)?;
+    character.deck.discard_from_hand(index);
+    Ok(abilities_gained)
+}
+
+pub fn play_card_unchecked(
+    character: &mut Character,
+    card_data: CardData,
+    registry: &Registry,
+) -> usize {
+    debug_assert!(can_afford(character, &card_data, registry));
+    match card_cost(character, &card_data, registry) {
+        CardCost::Stamina(cost) => {
+            character.stamina_current = usize::saturating_sub(character.stamina_current, cost);
+        }
+        // Paying is not damage, so it does not trigger anything
+        CardCost::Health(cost) => {
+            character.health_current = usize::saturating_sub(character.health_current, cost);
+        }
+    }
+    let number_of_abilities_gained = card_data.abilities.len();
+    // remaining_abilities is popped from the back, so the card's abilities are pushed in reverse to resolve in written order
+    character
+        .remaining_abilities
+        .extend(card_data.abilities.into_iter().rev());
     number_of_abilities_gained
 }
 
-pub fn end_turn(character: &mut Character) {
-    deal_damage(character.conditions.get(&Condition::Poison), character);
-    restore_health(character.conditions.get(&Condition::Regen), character);
+pub fn end_turn(character: &mut Character, registry: &Registry, observer: &mut dyn Observer) {
+    deal_damage(
+        character.conditions.get(&Condition::Poison),
+        character,
+        None,
+        registry,
+        observer,
+    );
+    restore_health(
+        character.conditions.get(&Condition::Regen),
+        character,
+        observer,
+    );
     character.turn_stats.end_turn();
-    push_triggered_abilities(character, |x| x.end_of_turn);
+    push_triggered_abilities(character, Trigger::EndOfTurn, registry, observer);
+    observer.on_event(&GameEvent::TurnEnded {
+        character: character.id,
+    });
+}
+
+// Returns false if the character loses the turn, which happens if it is Stunned at the start of it.
+pub fn begin_turn(
+    character: &mut Character,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> bool {
+    observer.on_event(&GameEvent::TurnBegan {
+        character: character.id,
+    });
+    let stunned = character.conditions.has(&Condition::Stunned);
+    for condition in character.conditions.decrement_all() {
+        observer.on_event(&GameEvent::ConditionExpired {
+            target: character.id,
+            condition,
+        });
+    }
+    push_triggered_abilities(character, Trigger::BeginningOfTurn, registry, observer);
+    !stunned
+}
+
+// Called for everyone alive at the start of every round, before the first turn of it.
+pub fn begin_round(character: &mut Character, registry: &Registry, observer: &mut dyn Observer) {
+    push_triggered_abilities(character, Trigger::BeginningOfRound, registry, observer);
 }
 
-pub fn begin_turn(character: &mut Character) {
-    character.conditions.decrement_all();
-    push_triggered_abilities(character, |x| x.beginning_of_turn);
+// Called for everyone alive after the last turn of every round.
+pub fn end_round(character: &mut Character, registry: &Registry, observer: &mut dyn Observer) {
+    push_triggered_abilities(character, Trigger::EndOfRound, registry, observer);
 }
@@ -1,16 +1,31 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use serde::{Deserialize, Serialize};
+
 use crate::{
-    data_model::{Action, ActionMovement, ActionOnSelf, ActionTargeted, CardData, Character},
-    play::{begin_turn, end_turn, play_card_unchecked},
-    pop_ability::pop_ability_ignore_unsatisfied,
+    board::Board,
+    data_model::{
+        Ability, Action, ActionMovement, ActionOnSelf, ActionTargeted, Character, CharacterId,
+    },
+    encounter::{EncounterOutcome, Objective, evaluate_encounter},
+    game_event::{GameEvent, Observer, RecordedInput},
+    monsters::{MonsterAbilityDeck, MonsterType, monster_abilities},
+    movement::MovementError,
+    play::{PlayCardError, begin_round, begin_turn, end_round, end_turn, play_card_from_hand},
+    pop_ability::{check_precondition_and_map_actions, chosen_actions, map_actions},
+    precondition::optional_precondition_is_met,
+    registry::Registry,
     resolve_action::{
         ActionInputMovement, ActionInputOnSelf, ActionInputTargeted, resolve_action_movement,
         resolve_action_on_self, resolve_action_targeted,
     },
+    rng::Rng,
     single_out::single_out,
+    targeting::{TargetError, legal_targets},
 };
 
 pub enum PlayCardOrEndTurn {
-    PlayCard(CardData),
+    PlayCard(usize), // Index into the hand of the character that has the turn.
     EndTurn,
 }
 
@@ -24,6 +39,14 @@ pub enum PendingInput<T> {
     Pending,
 }
 
+// The answer to an optional or choose one ability, see AbilityKind.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AbilityChoice {
+    Use,           // Resolve an optional ability.
+    Decline,       // Skip an optional ability.
+    Choose(usize), // Index into the options of a choose one ability.
+}
+
 #[rustfmt::skip]
 pub trait Input {
     fn poll_action_input_on_self(&mut self, action: &ActionOnSelf) -> PendingInput<ActionInputOnSelf>;
@@ -33,14 +56,166 @@ pub trait Input {
     fn poll_action_input_targeted_cancelable(&mut self, action: &ActionTargeted) -> PendingInput<Cancelable<ActionInputTargeted>>;
     fn poll_action_input_movement_cancelable(&mut self, action: &ActionMovement) -> PendingInput<Cancelable<ActionInputMovement>>;
     fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn>;
+    fn poll_ability_choice(&mut self, ability: &Ability) -> PendingInput<AbilityChoice>;
+}
+
+// Every turn goes through the phases in order. Abilities triggered in a phase are fully resolved
+// before the next phase starts. An encounter starts in the cleanup phase, so that the first turn is
+// passed on like every other.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TurnPhase {
+    // Beginning of turn triggers resolve.
+    BeginTurn,
+    // The character that has the turn plays cards until it ends the turn.
+    Main,
+    // End of turn triggers resolve.
+    EndTurn,
+    // The turn is passed on, and rounds are ended and started.
+    #[default]
+    Cleanup,
+}
+
+// An ability waiting on the trigger stack, together with the index of the character resolving it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StackedAbility {
+    pub character: usize,
+    pub ability: Ability,
+    pub pushed_after_death: bool, // On death abilities still resolve, everything else of a dead character is dropped.
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayState {
     active: usize,
     has_turn: usize,
     remaining_actions: Vec<Action>,
     cancelable: bool,
+    turn_lost: bool, // The character that has the turn was Stunned at the start of it.
+    objectives: Vec<Objective>,
+    outcome: EncounterOutcome,
+    rng: Rng,
+    turn: usize, // Number of turns ended so far.
+    #[serde(default)]
+    round: usize, // Number of rounds ended so far.
+    #[serde(default)]
+    round_started: bool,
+    #[serde(default)]
+    turn_order: Vec<usize>, // Everyone alive at the start of the round, by initiative.
+    #[serde(default)]
+    turn_order_position: usize, // Number of turns started in the round.
+    #[serde(default)]
+    monster_level: usize,
+    #[serde(default)]
+    monster_decks: BTreeMap<MonsterType, MonsterAbilityDeck>,
+    #[serde(default)]
+    monster_card_played: bool, // The monster that has the turn has put its ability card into play.
+    #[serde(default)]
+    phase: TurnPhase,
+    #[serde(default)]
+    trigger_stack: Vec<StackedAbility>, // Resolved from the back.
+    #[serde(default)]
+    pending_choice: Option<StackedAbility>, // Popped from the trigger stack, waiting for its choice.
+}
+
+impl Default for PlayState {
+    fn default() -> Self {
+        Self::with_objectives(vec![Objective::DefeatAllMonsters])
+    }
+}
+
+impl PlayState {
+    pub fn with_objectives(objectives: Vec<Objective>) -> Self {
+        Self {
+            active: 0,
+            has_turn: 0,
+            remaining_actions: Vec::new(),
+            cancelable: false,
+            turn_lost: false,
+            objectives,
+            outcome: EncounterOutcome::InProgress,
+            rng: Rng::default(),
+            turn: 0,
+            round: 0,
+            round_started: false,
+            turn_order: Vec::new(),
+            turn_order_position: 0,
+            monster_level: 0,
+            monster_decks: BTreeMap::new(),
+            monster_card_played: false,
+            phase: TurnPhase::Cleanup,
+            trigger_stack: Vec::new(),
+            pending_choice: None,
+        }
+    }
+
+    pub fn with_monster_level(self, monster_level: usize) -> Self {
+        Self {
+            monster_level,
+            ..self
+        }
+    }
+
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            rng: Rng::new(seed),
+            ..self
+        }
+    }
+
+    pub fn outcome(&self) -> &EncounterOutcome {
+        &self.outcome
+    }
+
+    pub fn turn(&self) -> usize {
+        self.turn
+    }
+
+    pub fn round(&self) -> usize {
+        self.round
+    }
+
+    // Empty until the first step.
+    pub fn turn_order(&self) -> &[usize] {
+        &self.turn_order
+    }
+
+    pub fn monster_level(&self) -> usize {
+        self.monster_level
+    }
+
+    // The ability card revealed this round for the monster type, as an index into its ability cards.
+    pub fn revealed_monster_card(&self, monster_type: MonsterType) -> Option<usize> {
+        self.monster_decks
+            .get(&monster_type)
+            .and_then(|deck| deck.revealed())
+    }
+
+    pub fn phase(&self) -> TurnPhase {
+        self.phase
+    }
+
+    pub fn trigger_stack(&self) -> &[StackedAbility] {
+        &self.trigger_stack
+    }
+
+    // The action the acting character resolves next, if any.
+    pub fn pending_action(&self) -> Option<&Action> {
+        self.remaining_actions.first()
+    }
+
+    pub fn has_turn(&self) -> usize {
+        self.has_turn
+    }
+
+    // The character whose input the next step may poll for.
+    pub fn acting(&self) -> usize {
+        if let Some(pending_choice) = &self.pending_choice {
+            pending_choice.character
+        } else if self.remaining_actions.is_empty() {
+            self.has_turn
+        } else {
+            self.active
+        }
+    }
 }
 
 enum ExecutionState {
@@ -49,30 +224,226 @@ enum ExecutionState {
     Canceled,
 }
 
+// Returned when the input for the current action is rejected. The action is left unresolved and
+// will be polled for again on the next step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum StepError {
+    InvalidMovement(MovementError),
+    InvalidTarget(TargetError),
+    CanNotPlayCard(PlayCardError),
+    InvalidAbilityChoice(AbilityChoice),
+}
+
+impl std::fmt::Display for StepError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StepError::InvalidMovement(err) => write!(f, "Invalid movement: {}", err),
+            StepError::InvalidTarget(err) => write!(f, "Invalid target: {}", err),
+            StepError::CanNotPlayCard(err) => write!(f, "Can not play the card: {}", err),
+            StepError::InvalidAbilityChoice(choice) => {
+                write!(f, "{:?} is not a choice the ability offers", choice)
+            }
+        }
+    }
+}
+
+impl From<MovementError> for StepError {
+    fn from(err: MovementError) -> Self {
+        StepError::InvalidMovement(err)
+    }
+}
+
+impl From<PlayCardError> for StepError {
+    fn from(err: PlayCardError) -> Self {
+        StepError::CanNotPlayCard(err)
+    }
+}
+
+impl From<TargetError> for StepError {
+    fn from(err: TargetError) -> Self {
+        StepError::InvalidTarget(err)
+    }
+}
+
+// Characters are told apart by their id, e.g. in deaths and objectives, so no two may share one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DuplicateCharacterId(pub CharacterId);
+
+impl std::fmt::Display for DuplicateCharacterId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "More than one character has the id {}", self.0.0)
+    }
+}
+
+// Shuffles every deck and draws the starting hands. Nothing is changed if two characters share an
+// id.
+pub fn start_encounter(
+    play_state: &mut PlayState,
+    all_characters: &mut [Character],
+) -> Result<(), DuplicateCharacterId> {
+    let mut ids = BTreeSet::new();
+    if let Some(duplicate) = all_characters
+        .iter()
+        .find(|character| !ids.insert(character.id))
+    {
+        return Err(DuplicateCharacterId(duplicate.id));
+    }
+    for character in all_characters {
+        character.deck.draw_starting_hand(&mut play_state.rng);
+    }
+    Ok(())
+}
+
+// Lower initiative acts first. Monsters with a type use the initiative of the card revealed for it.
+pub fn initiative(play_state: &PlayState, character: &Character) -> usize {
+    character
+        .monster_type
+        .and_then(|monster_type| {
+            play_state
+                .revealed_monster_card(monster_type)
+                .map(|card| monster_type.data().ability_cards[card].initiative)
+        })
+        .unwrap_or(character.initiative)
+}
+
+// Reveals the next ability card of every monster type with a living monster, and orders everyone
+// alive by initiative. Ties keep the order of the characters.
+fn start_round(
+    play_state: &mut PlayState,
+    all_characters: &mut [Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    play_state.round_started = true;
+    let monster_types: BTreeSet<MonsterType> = all_characters
+        .iter()
+        .filter(|character| character.is_alive())
+        .filter_map(|character| character.monster_type)
+        .collect();
+    for monster_type in monster_types {
+        let deck = play_state
+            .monster_decks
+            .entry(monster_type)
+            .or_insert_with(|| MonsterAbilityDeck::new(monster_type));
+        if let Some(card) = deck.reveal(monster_type, &mut play_state.rng) {
+            observer.on_event(&GameEvent::MonsterCardRevealed { monster_type, card });
+        }
+    }
+
+    let mut turn_order: Vec<usize> = (0..all_characters.len())
+        .filter(|index| all_characters[*index].is_alive())
+        .collect();
+    turn_order.sort_by_key(|index| initiative(play_state, &all_characters[*index]));
+    play_state.turn_order = turn_order;
+    play_state.turn_order_position = 0;
+    observer.on_event(&GameEvent::RoundBegan {
+        round: play_state.round,
+        turn_order: play_state
+            .turn_order
+            .iter()
+            .map(|index| all_characters[*index].id)
+            .collect(),
+    });
+    for index in &play_state.turn_order {
+        begin_round(&mut all_characters[*index], registry, observer);
+    }
+}
+
+fn finish_round(
+    play_state: &mut PlayState,
+    all_characters: &mut [Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    for character in all_characters.iter_mut() {
+        end_round(character, registry, observer);
+    }
+    observer.on_event(&GameEvent::RoundEnded {
+        round: play_state.round,
+    });
+    play_state.round += 1;
+    play_state.round_started = false;
+}
+
+// The next living character in the turn order of the current round.
+fn next_in_turn_order(play_state: &mut PlayState, all_characters: &[Character]) -> Option<usize> {
+    while play_state.turn_order_position < play_state.turn_order.len() {
+        let index = play_state.turn_order[play_state.turn_order_position];
+        play_state.turn_order_position += 1;
+        if all_characters[index].is_alive() {
+            return Some(index);
+        }
+    }
+    None
+}
+
+fn play_monster_card(play_state: &PlayState, character: &mut Character, monster_type: MonsterType) {
+    let Some(card) = play_state.revealed_monster_card(monster_type) else {
+        return;
+    };
+    let abilities = monster_abilities(
+        &monster_type.data().ability_cards[card],
+        &monster_type.stats(play_state.monster_level),
+    );
+    // remaining_abilities is popped from the back
+    character
+        .remaining_abilities
+        .extend(abilities.into_iter().rev());
+}
+
+// Once the encounter has ended, stepping does nothing.
 pub fn step_play_state(
     play_state: &mut PlayState,
     input: &mut impl Input,
+    board: &Board,
     all_characters: &mut [Character],
-) {
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<(), StepError> {
+    if play_state.outcome != EncounterOutcome::InProgress {
+        return Ok(());
+    }
+    let result =
+        step_play_state_in_progress(play_state, input, board, all_characters, registry, observer);
+    stack_pushed_abilities(play_state, all_characters);
+    play_state.outcome = evaluate_encounter(&play_state.objectives, all_characters);
+    if play_state.outcome != EncounterOutcome::InProgress {
+        observer.on_event(&GameEvent::EncounterEnded(play_state.outcome.clone()));
+    }
+    result
+}
+
+fn step_play_state_in_progress(
+    play_state: &mut PlayState,
+    input: &mut impl Input,
+    board: &Board,
+    all_characters: &mut [Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<(), StepError> {
     let Some((active_character, mut characters)) = single_out(all_characters, play_state.active)
     else {
         debug_assert!(false);
-        return;
+        return Ok(());
     };
 
     match play_state.remaining_actions.first() {
         Some(action) => {
+            let was_alive = active_character.is_alive();
             let execution_state = execute_action(
                 action,
                 play_state.cancelable,
                 input,
+                board,
                 active_character,
                 &mut characters,
-            );
+                registry,
+                observer,
+            )?;
 
             match execution_state {
                 ExecutionState::Executed => {
-                    play_state.remaining_actions.pop();
+                    play_state.remaining_actions.remove(0);
                     play_state.cancelable = false;
                 }
                 ExecutionState::Canceled => {
@@ -80,109 +451,318 @@ pub fn step_play_state(
                 }
                 ExecutionState::Waiting => {}
             }
+            // A character that dies while acting, e.g. from Retaliate, performs no further actions.
+            if was_alive && !active_character.is_alive() {
+                play_state.remaining_actions.clear();
+            }
         }
         None => {
-            let last_active = play_state.active;
-            loop {
-                play_state.active = (play_state.active + 1) % all_characters.len();
+            if let Some(pending_choice) = &play_state.pending_choice {
+                let choice = match input.poll_ability_choice(&pending_choice.ability) {
+                    PendingInput::Some(choice) => choice,
+                    PendingInput::Pending => return Ok(()),
+                };
+                let Some(actions) = chosen_actions(&pending_choice.ability, choice) else {
+                    return Err(StepError::InvalidAbilityChoice(choice));
+                };
+                observer.on_event(&GameEvent::Input(RecordedInput::AbilityChoice(choice)));
+                play_state.active = pending_choice.character;
+                play_state.remaining_actions =
+                    map_actions(&all_characters[play_state.active], actions.into_iter());
+                play_state.cancelable = true;
+                play_state.pending_choice = None;
+                return Ok(());
+            }
 
-                let Some((active_character, characters)) =
-                    single_out(all_characters, play_state.active)
+            // Abilities on the trigger stack resolve before the turn can go on, the most recently
+            // triggered first.
+            while let Some(stacked) = play_state.trigger_stack.pop() {
+                let Some((character, characters)) = single_out(all_characters, stacked.character)
                 else {
                     debug_assert!(false);
-                    break;
+                    continue;
                 };
-
-                match pop_ability_ignore_unsatisfied(active_character, &characters) {
-                    Some(actions) => {
-                        play_state.remaining_actions = actions;
-                        play_state.cancelable = true;
-                        break;
-                    }
-                    None => {
-                        if play_state.active == last_active {
-                            play_state.active = play_state.has_turn;
-                            match input.poll_play_card_or_end_turn() {
-                                PendingInput::Some(PlayCardOrEndTurn::PlayCard(card_data)) => {
-                                    play_card_unchecked(active_character, card_data); // TODO: checks
-                                }
-                                PendingInput::Some(PlayCardOrEndTurn::EndTurn) => {
-                                    end_turn(active_character);
-                                    play_state.has_turn =
-                                        (play_state.has_turn + 1) % all_characters.len();
-                                    //TODO: End of turn triggers will have weird ordering with the beginning of turn effects in the current implementation.
-                                    begin_turn(
-                                        all_characters.get_mut(play_state.has_turn).unwrap(),
-                                    );
-                                }
-                                PendingInput::Pending => {}
-                            }
-                        }
+                if !character.is_alive() && !stacked.pushed_after_death {
+                    continue;
+                }
+                // The choice is polled for on the next step
+                if !stacked.ability.kind.is_mandatory() {
+                    if optional_precondition_is_met(
+                        stacked.ability.precondition.as_ref(),
+                        &characters,
+                        character,
+                    ) {
+                        play_state.pending_choice = Some(stacked);
+                        return Ok(());
                     }
+                    continue;
+                }
+                if let Some(actions) =
+                    check_precondition_and_map_actions(stacked.ability, character, &characters)
+                {
+                    play_state.active = stacked.character;
+                    play_state.remaining_actions = actions;
+                    play_state.cancelable = true;
+                    return Ok(());
+                }
+            }
+
+            play_state.active = play_state.has_turn;
+            match play_state.phase {
+                TurnPhase::BeginTurn => play_state.phase = TurnPhase::Main,
+                TurnPhase::Main => {
+                    step_main_phase(play_state, input, all_characters, registry, observer)?
                 }
+                TurnPhase::EndTurn => {
+                    play_state.turn += 1;
+                    play_state.phase = TurnPhase::Cleanup;
+                }
+                TurnPhase::Cleanup => {
+                    step_cleanup_phase(play_state, all_characters, registry, observer)
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+// Moves the abilities the characters pushed during the last step onto the trigger stack. Of the
+// abilities pushed in the same step, those of the character that has the turn resolve last, and
+// those of everyone else in character order after it.
+fn stack_pushed_abilities(play_state: &mut PlayState, all_characters: &mut [Character]) {
+    let character_count = all_characters.len();
+    for offset in [0].into_iter().chain((1..character_count).rev()) {
+        let index = (play_state.has_turn + offset) % character_count;
+        let character = &mut all_characters[index];
+        let pushed_after_death = !character.is_alive();
+        play_state
+            .trigger_stack
+            .extend(
+                character
+                    .remaining_abilities
+                    .drain(..)
+                    .map(|ability| StackedAbility {
+                        character: index,
+                        ability,
+                        pushed_after_death,
+                    }),
+            );
+    }
+}
+
+fn step_main_phase(
+    play_state: &mut PlayState,
+    input: &mut impl Input,
+    all_characters: &mut [Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<(), StepError> {
+    let turn_over = play_state.turn_lost
+        || play_state.monster_card_played
+        || !all_characters[play_state.has_turn].is_alive();
+    // Monsters with a type play the revealed card of their type, and their turn ends once it has been
+    // resolved.
+    if !turn_over && let Some(monster_type) = all_characters[play_state.has_turn].monster_type {
+        play_monster_card(
+            play_state,
+            &mut all_characters[play_state.has_turn],
+            monster_type,
+        );
+        play_state.monster_card_played = true;
+        return Ok(());
+    }
+    let play_card_or_end_turn = if turn_over {
+        PendingInput::Some(PlayCardOrEndTurn::EndTurn)
+    } else {
+        input.poll_play_card_or_end_turn()
+    };
+    let active_character = &mut all_characters[play_state.has_turn];
+    match play_card_or_end_turn {
+        PendingInput::Some(PlayCardOrEndTurn::PlayCard(index)) => {
+            let card = active_character.deck.card_in_hand(index);
+            play_card_from_hand(active_character, index, registry)?;
+            observer.on_event(&GameEvent::Input(RecordedInput::PlayCard(index)));
+            if let Some(card) = card {
+                observer.on_event(&GameEvent::CardPlayed {
+                    character: active_character.id,
+                    card,
+                });
+            }
+        }
+        PendingInput::Some(PlayCardOrEndTurn::EndTurn) => {
+            if !turn_over {
+                observer.on_event(&GameEvent::Input(RecordedInput::EndTurn));
             }
+            end_turn(active_character, registry, observer);
+            play_state.phase = TurnPhase::EndTurn;
         }
+        PendingInput::Pending => {}
     }
+    Ok(())
 }
 
+// Passes the turn to the next character in the turn order. After the last turn of a round, the
+// round is ended and the next one started first, each in its own step so that the round triggers
+// resolve in between.
+fn step_cleanup_phase(
+    play_state: &mut PlayState,
+    all_characters: &mut [Character],
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) {
+    if !play_state.round_started {
+        start_round(play_state, all_characters, registry, observer);
+        return;
+    }
+    let Some(next) = next_in_turn_order(play_state, all_characters) else {
+        finish_round(play_state, all_characters, registry, observer);
+        return;
+    };
+    play_state.has_turn = next;
+    play_state.active = next;
+    play_state.monster_card_played = false;
+    let next_character = &mut all_characters[next];
+    play_state.turn_lost = !begin_turn(next_character, registry, observer);
+    // The starting hands are drawn when the encounter starts
+    if play_state.turn > 0 {
+        next_character.deck.draw_for_turn(&mut play_state.rng);
+    }
+    play_state.phase = TurnPhase::BeginTurn;
+}
+
+#[allow(clippy::too_many_arguments)]
 fn execute_action<C>(
     action: &Action,
     cancelable: bool,
     input_state: &mut impl Input,
+    board: &Board,
     active_character: &mut Character,
     characters: &mut C,
-) -> ExecutionState
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<ExecutionState, StepError>
 where
     for<'c> &'c mut C: IntoIterator<Item = &'c mut Character>,
 {
     match (action, cancelable) {
-        (Action::OnSelf(action), false) => {
-            resolve_action_on_self(action, active_character, characters);
-            ExecutionState::Executed
-        }
+        (Action::OnSelf(action), false) => match input_state.poll_action_input_on_self(action) {
+            PendingInput::Some(_) => {
+                resolve_action_on_self(
+                    action,
+                    board,
+                    active_character,
+                    characters,
+                    registry,
+                    observer,
+                );
+                Ok(ExecutionState::Executed)
+            }
+            PendingInput::Pending => Ok(ExecutionState::Waiting),
+        },
         (Action::OnSelf(action), true) => {
             match input_state.poll_action_input_on_self_cancelable(action) {
                 PendingInput::Some(Cancelable::Some(_)) => {
-                    resolve_action_on_self(action, active_character, characters);
-                    ExecutionState::Executed
+                    observer.on_event(&GameEvent::Input(RecordedInput::Confirm));
+                    resolve_action_on_self(
+                        action,
+                        board,
+                        active_character,
+                        characters,
+                        registry,
+                        observer,
+                    );
+                    Ok(ExecutionState::Executed)
+                }
+                PendingInput::Some(Cancelable::Canceled) => {
+                    observer.on_event(&GameEvent::Input(RecordedInput::Cancel));
+                    Ok(ExecutionState::Canceled)
                 }
-                PendingInput::Some(Cancelable::Canceled) => ExecutionState::Canceled,
-                PendingInput::Pending => ExecutionState::Waiting,
+                PendingInput::Pending => Ok(ExecutionState::Waiting),
             }
         }
-        (Action::Targeted(action), false) => match input_state.poll_action_input_targeted(action) {
-            PendingInput::Some(input) => {
-                resolve_action_targeted(action, &input, active_character, characters);
-                ExecutionState::Executed
+        (Action::Targeted(action), false) => {
+            // An action that can not be canceled is dropped when nothing can be targeted, instead
+            // of waiting for a target that does not exist
+            let others: Vec<&Character> = characters
+                .into_iter()
+                .map(|c| &*c)
+                .filter(|c| c.is_alive())
+                .collect();
+            if legal_targets(action, board, active_character, &others).is_empty() {
+                return Ok(ExecutionState::Executed);
             }
-            PendingInput::Pending => ExecutionState::Waiting,
-        },
+            match input_state.poll_action_input_targeted(action) {
+                PendingInput::Some(input) => {
+                    resolve_action_targeted(
+                        action,
+                        &input,
+                        board,
+                        active_character,
+                        characters,
+                        registry,
+                        observer,
+                    )?;
+                    Ok(ExecutionState::Executed)
+                }
+                PendingInput::Pending => Ok(ExecutionState::Waiting),
+            }
+        }
         (Action::Targeted(action), true) => {
             match input_state.poll_action_input_targeted_cancelable(action) {
                 PendingInput::Some(Cancelable::Some(input)) => {
-                    resolve_action_targeted(action, &input, active_character, characters);
-                    ExecutionState::Executed
+                    resolve_action_targeted(
+                        action,
+                        &input,
+                        board,
+                        active_character,
+                        characters,
+                        registry,
+                        observer,
+                    )?;
+                    Ok(ExecutionState::Executed)
                 }
 
-                PendingInput::Some(Cancelable::Canceled) => ExecutionState::Canceled,
-                PendingInput::Pending => ExecutionState::Waiting,
+                PendingInput::Some(Cancelable::Canceled) => {
+                    observer.on_event(&GameEvent::Input(RecordedInput::Cancel));
+                    Ok(ExecutionState::Canceled)
+                }
+                PendingInput::Pending => Ok(ExecutionState::Waiting),
             }
         }
         (Action::Movement(action), false) => match input_state.poll_action_input_movement(action) {
             PendingInput::Some(input) => {
-                resolve_action_movement(action, &input, active_character);
-                ExecutionState::Executed
+                resolve_action_movement(
+                    action,
+                    &input,
+                    board,
+                    active_character,
+                    characters,
+                    registry,
+                    observer,
+                )?;
+                Ok(ExecutionState::Executed)
             }
-            PendingInput::Pending => ExecutionState::Waiting,
+            PendingInput::Pending => Ok(ExecutionState::Waiting),
         },
         (Action::Movement(action), true) => {
             match input_state.poll_action_input_movement_cancelable(action) {
                 PendingInput::Some(Cancelable::Some(input)) => {
-                    resolve_action_movement(action, &input, active_character);
-                    ExecutionState::Executed
+                    resolve_action_movement(
+                        action,
+                        &input,
+                        board,
+                        active_character,
+                        characters,
+                        registry,
+                        observer,
+                    )?;
+                    Ok(ExecutionState::Executed)
+                }
+                PendingInput::Some(Cancelable::Canceled) => {
+                    observer.on_event(&GameEvent::Input(RecordedInput::Cancel));
+                    Ok(ExecutionState::Canceled)
                 }
-                PendingInput::Some(Cancelable::Canceled) => ExecutionState::Canceled,
-                PendingInput::Pending => ExecutionState::Waiting,
+                PendingInput::Pending => Ok(ExecutionState::Waiting),
             }
         }
     }
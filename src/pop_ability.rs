@@ -1,74 +1,118 @@
 use crate::{
-    data_model::{Action, ActionOnSelf, ActionTargeted, AreaEffect, Character, Condition, Reach},
+    data_model::{
+        Ability, AbilityKind, Action, ActionMovement, ActionOnSelf, ActionTargeted, AreaEffect,
+        Character, Condition, EffectOnCharacter, Reach,
+    },
     hex_grid::{Area, DistanceRange},
+    play_state::AbilityChoice,
     precondition::optional_precondition_is_met,
 };
 
-pub enum PopAbilityResult {
-    NoRemainingAbilities,
-    NextAbilityDoesNotSatisfyPrecondition,
-    Actions { actions: Vec<Action> },
+// The actions of an optional or choose one ability for the choice made. None if the choice does not
+// fit the kind of ability.
+pub fn chosen_actions(ability: &Ability, choice: AbilityChoice) -> Option<Vec<Action>> {
+    match (&ability.kind, choice) {
+        (AbilityKind::Optional, AbilityChoice::Use) => Some(ability.actions.clone()),
+        (AbilityKind::Optional, AbilityChoice::Decline) => Some(Vec::new()),
+        (AbilityKind::ChooseOne(options), AbilityChoice::Choose(index)) => options
+            .get(index)
+            .map(|option| ability.actions.iter().chain(option).cloned().collect()),
+        _ => None,
+    }
 }
 
-pub fn pop_ability_ignore_unsatisfied<C>(
-    source_character: &mut Character,
-    characters: &C,
+// Returns None if the precondition of the ability is not met.
+pub fn check_precondition_and_map_actions<'a, C>(
+    ability: Ability,
+    source_character: &'a Character,
+    characters: C,
 ) -> Option<Vec<Action>>
 where
-    for<'c> &'c C: IntoIterator<Item = &'c Character>,
+    C: IntoIterator<Item = &'a Character>,
 {
-    loop {
-        match pop_ability(source_character, characters) {
-            PopAbilityResult::NoRemainingAbilities => break None,
-            PopAbilityResult::NextAbilityDoesNotSatisfyPrecondition => {}
-            PopAbilityResult::Actions { actions } => break Some(actions),
-        }
-    }
+    optional_precondition_is_met(ability.precondition.as_ref(), characters, source_character)
+        .then(|| map_actions(source_character, ability.actions.into_iter()))
 }
 
-pub fn pop_ability<'a, C>(source_character: &'a mut Character, characters: C) -> PopAbilityResult
+// If a lot of mechanics require action mapping, a generic data model for mapping an action should be considered
+
+// Stunned characters can't perform any actions, so all of them are dropped.
+pub fn map_actions<A>(character: &Character, actions: A) -> Vec<Action>
 where
-    C: IntoIterator<Item = &'a Character>,
+    A: Iterator<Item = Action>,
 {
-    match source_character.remaining_abilities.pop() {
-        Some(mut ability)
-            if optional_precondition_is_met(
-                ability.precondition.as_ref(),
-                characters,
-                source_character,
-            ) =>
-        {
-            PopAbilityResult::Actions {
-                actions: ability
-                    .actions
-                    .drain(..)
-                    .map(|action| map_action(source_character, action))
-                    .collect(),
-            }
-        }
-        Some(_) => PopAbilityResult::NextAbilityDoesNotSatisfyPrecondition,
-        None => PopAbilityResult::NoRemainingAbilities,
+    if character.conditions.has(&Condition::Stunned) {
+        return vec![];
     }
+    actions
+        .filter_map(|action| map_action(character, action))
+        .collect()
 }
-/*
-pub fn check_precondition_and_map_actions(ability: Ability, source_character: &mut Character, characters: C) -> Option<Vec<Action>>
-where
-    C: IntoIterator<Item = &'a Character>,
-{
-
-}
-*/
 
-// If a lot of mechanics require action mapping, a generic data model for mapping an action should be considered
-
-pub fn map_action(character: &Character, action: Action) -> Action {
-    if character.conditions.has(&Condition::Fury) {
+// Returns None if the action is dropped entirely.
+pub fn map_action(character: &Character, action: Action) -> Option<Action> {
+    let action = if character.conditions.has(&Condition::Fury) {
         match filter_map_action_for_fury(&action) {
             Some(mapped_action) => mapped_action,
             None => action,
         }
     } else {
         action
+    };
+    let action = if character.conditions.has(&Condition::Disarmed) {
+        filter_map_action_for_disarmed(action)?
+    } else {
+        action
+    };
+    Some(map_action_for_movement_conditions(character, action))
+}
+
+// Removes all attacks, i.e. damage effects. Actions that are left without any effects are dropped.
+pub fn filter_map_action_for_disarmed(action: Action) -> Option<Action> {
+    let without_attacks = |effects: Vec<AreaEffect>| -> Vec<AreaEffect> {
+        effects
+            .into_iter()
+            .map(|area_effect| AreaEffect {
+                effects: area_effect
+                    .effects
+                    .into_iter()
+                    .filter(|effect| {
+                        !matches!(
+                            effect,
+                            EffectOnCharacter::Damage(_)
+                                | EffectOnCharacter::DamageWithLifesteal(_)
+                        )
+                    })
+                    .collect(),
+                ..area_effect
+            })
+            .filter(|area_effect| !area_effect.effects.is_empty())
+            .collect()
+    };
+    match action {
+        Action::OnSelf(ActionOnSelf { effects }) => {
+            let effects = without_attacks(effects);
+            (!effects.is_empty()).then_some(Action::OnSelf(ActionOnSelf { effects }))
+        }
+        Action::Targeted(ActionTargeted { reach, effects }) => {
+            let effects = without_attacks(effects);
+            (!effects.is_empty()).then_some(Action::Targeted(ActionTargeted { reach, effects }))
+        }
+        Action::Movement(_) => Some(action),
+    }
+}
+
+// Applies Slow and Fast to the number of spaces of movement actions.
+pub fn map_action_for_movement_conditions(character: &Character, action: Action) -> Action {
+    match action {
+        Action::Movement(ActionMovement { spaces, jump }) => Action::Movement(ActionMovement {
+            spaces: usize::saturating_sub(
+                spaces + character.conditions.get(&Condition::Fast),
+                character.conditions.get(&Condition::Slow),
+            ),
+            jump,
+        }),
+        _ => action,
     }
 }
 
@@ -87,8 +131,7 @@ pub fn filter_map_action_for_fury(action: &Action) -> Option<Action> {
                     .iter()
                     .map(|effect| AreaEffect {
                         area: Area::Disk(DistanceRange { from: 1, to: 2 }),
-                        filter: effect.filter.clone(),
-                        effects: effect.effects.clone(),
+                        ..effect.clone()
                     })
                     .collect(),
             }))
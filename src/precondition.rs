@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::iter;
 
 use crate::{
@@ -6,7 +7,7 @@ use crate::{
     turn_stats::TurnStat,
 };
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Precondition {
     FilteredCount {
         filter: CharacterFilter,
@@ -49,7 +50,9 @@ where
             let count = characters
                 .into_iter()
                 .chain(iter::once(source_character))
-                .filter(|character| filter_character(character, filter, source_character))
+                .filter(|character| {
+                    character.is_alive() && filter_character(character, filter, source_character)
+                })
                 .count();
             comparison.compare(&count, value)
         }
@@ -0,0 +1,232 @@
+#![allow(dead_code)]
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::Path,
+};
+
+use ron::{Options, extensions::Extensions, ser::PrettyConfig};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cards::Card,
+    data_model::{
+        Ability, AbilityKind, Action, AreaEffect, CardData, EffectOnCharacter, ItemData, Reach,
+    },
+    hex_grid::Area,
+    items::Item,
+};
+
+// The layout of a definition file. Both maps may be left out.
+#[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DefinitionFile {
+    pub cards: BTreeMap<String, CardData>,
+    pub items: BTreeMap<String, ItemData>,
+}
+
+// The built-in cards and items. Balancing them only means editing this file.
+const BUILTIN: &str = include_str!("../data/builtin.ron");
+
+// Maps names to card and item definitions. Definitions loaded later replace earlier ones with the
+// same name, so a file can override single built-ins.
+#[derive(Default, Debug, Clone)]
+pub struct Registry {
+    cards: HashMap<String, CardData>,
+    items: HashMap<String, ItemData>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RegistryError {
+    pub file: String,
+    pub line: usize, // 1-based. 0 if the error is not tied to a position in the file.
+    pub column: usize,
+    pub kind: RegistryErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RegistryErrorKind {
+    Io(String),
+    Parse(String),
+    Invalid { name: String, message: String },
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}: ", self.file, self.line, self.column)?;
+        match &self.kind {
+            RegistryErrorKind::Io(message) | RegistryErrorKind::Parse(message) => {
+                write!(f, "{}", message)
+            }
+            RegistryErrorKind::Invalid { name, message } => write!(f, "{}: {}", name, message),
+        }
+    }
+}
+
+impl Registry {
+    pub fn builtin() -> Self {
+        let mut registry = Self::default();
+        registry
+            .load_str("data/builtin.ron", BUILTIN)
+            .expect("The built-in definitions are valid");
+        registry
+    }
+
+    pub fn card(&self, card: &Card) -> Option<&CardData> {
+        self.cards.get(card.name())
+    }
+
+    pub fn item(&self, item: &Item) -> Option<&ItemData> {
+        self.items.get(item.name())
+    }
+
+    pub fn card_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.cards.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    pub fn item_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.items.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+
+    pub fn load_file(&mut self, path: &Path) -> Result<(), RegistryError> {
+        let file = path.display().to_string();
+        let source = std::fs::read_to_string(path).map_err(|err| RegistryError {
+            file: file.clone(),
+            line: 0,
+            column: 0,
+            kind: RegistryErrorKind::Io(err.to_string()),
+        })?;
+        self.load_str(&file, &source)
+    }
+
+    // Nothing is added unless every definition in the source is valid.
+    pub fn load_str(&mut self, file: &str, source: &str) -> Result<(), RegistryError> {
+        let definitions: DefinitionFile =
+            ron_options()
+                .from_str(source)
+                .map_err(|err| RegistryError {
+                    file: file.into(),
+                    line: err.span.start.line,
+                    column: err.span.start.col,
+                    kind: RegistryErrorKind::Parse(err.code.to_string()),
+                })?;
+
+        let invalid = |name: &str, message: String| {
+            let (line, column) = position_of_name(source, name);
+            RegistryError {
+                file: file.into(),
+                line,
+                column,
+                kind: RegistryErrorKind::Invalid {
+                    name: name.into(),
+                    message,
+                },
+            }
+        };
+        for (name, card_data) in &definitions.cards {
+            validate_abilities(&card_data.abilities).map_err(|message| invalid(name, message))?;
+        }
+        for (name, item_data) in &definitions.items {
+            let triggered_abilities = &item_data.triggered_abilities;
+            for abilities in [
+                &triggered_abilities.damage_taken,
+                &triggered_abilities.attack_action,
+                &triggered_abilities.movement_action,
+                &triggered_abilities.beginning_of_turn,
+                &triggered_abilities.end_of_turn,
+                &triggered_abilities.on_kill,
+                &triggered_abilities.on_death,
+                &triggered_abilities.beginning_of_round,
+                &triggered_abilities.end_of_round,
+            ] {
+                validate_abilities(abilities).map_err(|message| invalid(name, message))?;
+            }
+        }
+
+        self.cards.extend(definitions.cards);
+        self.items.extend(definitions.items);
+        Ok(())
+    }
+
+    pub fn to_ron(&self) -> String {
+        let definitions = DefinitionFile {
+            cards: self.cards.clone().into_iter().collect(),
+            items: self.items.clone().into_iter().collect(),
+        };
+        ron_options()
+            .to_string_pretty(&definitions, PrettyConfig::new())
+            .expect("Definitions are serializable")
+    }
+}
+
+// Optional values can be written without Some(..), and the variants wrapping a struct without the
+// extra parentheses, e.g. `Condition(condition_type: Poison, value: 2)`.
+fn ron_options() -> Options {
+    Options::default()
+        .with_default_extension(Extensions::IMPLICIT_SOME | Extensions::UNWRAP_VARIANT_NEWTYPES)
+}
+
+// The position of the first quoted occurrence of the name, i.e. where it is defined.
+fn position_of_name(source: &str, name: &str) -> (usize, usize) {
+    let Some(offset) = source.find(&format!("\"{}\"", name)) else {
+        return (0, 0);
+    };
+    let before = &source[..offset];
+    let line = before.matches('\n').count() + 1;
+    let column = offset - before.rfind('\n').map_or(0, |newline| newline + 1) + 1;
+    (line, column)
+}
+
+fn validate_abilities(abilities: &[Ability]) -> Result<(), String> {
+    for ability in abilities {
+        let options: &[Vec<Action>] = match &ability.kind {
+            AbilityKind::ChooseOne(options) if options.is_empty() => {
+                return Err("A choose one ability must have at least 1 option".into());
+            }
+            AbilityKind::ChooseOne(options) => options,
+            _ => &[],
+        };
+        for action in ability.actions.iter().chain(options.iter().flatten()) {
+            match action {
+                Action::OnSelf(action) => validate_area_effects(&action.effects, false)?,
+                Action::Targeted(action) => {
+                    if action.reach == (Reach::Ranged { range: 0 }) {
+                        return Err("A ranged action must have a range of at least 1".into());
+                    }
+                    validate_area_effects(&action.effects, false)?
+                }
+                Action::Movement(action) => {
+                    if action.spaces == 0 {
+                        return Err("A movement action must move at least 1 space".into());
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn validate_area_effects(area_effects: &[AreaEffect], in_on_kill: bool) -> Result<(), String> {
+    for area_effect in area_effects {
+        let Area::Disk(distance_range) = &area_effect.area;
+        if distance_range.from >= distance_range.to {
+            return Err(format!(
+                "The area {}..{} does not contain any hexes",
+                distance_range.from, distance_range.to
+            ));
+        }
+        if !in_on_kill
+            && area_effect
+                .effects
+                .contains(&EffectOnCharacter::OverkillDamage)
+        {
+            return Err("OverkillDamage can only be used in on_kill".into());
+        }
+        validate_area_effects(&area_effect.on_kill, true)?;
+    }
+    Ok(())
+}
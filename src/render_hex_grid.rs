@@ -5,6 +5,26 @@ pub trait HexContent {
     fn hex_content(&self, pos: &PosOddQHex, content_row: isize) -> String;
 }
 
+// Content may be colored with ANSI escape sequences, which take no space on the screen.
+fn is_escape_start(c: char) -> bool {
+    c == '\x1b'
+}
+
+pub fn visible_char_count(s: &str) -> usize {
+    let mut count = 0;
+    let mut in_escape = false;
+    for c in s.chars() {
+        if is_escape_start(c) {
+            in_escape = true;
+        } else if in_escape {
+            in_escape = !c.is_ascii_alphabetic();
+        } else {
+            count += 1;
+        }
+    }
+    count
+}
+
 fn push_char(output: &mut String, c: char, count: usize) {
     for _ in 0..count {
         output.push(c);
@@ -23,12 +43,24 @@ fn push_content_row<'a, C>(
 {
     let s = hex_content.hex_content(&PosOddQHex { q, r }, content_row);
 
-    let char_count = s.chars().count();
+    let char_count = visible_char_count(&s);
     let pad = usize::saturating_sub(length, char_count);
     let left_pad = pad / 2;
     let right_pad = pad - left_pad;
     push_char(output, ' ', left_pad);
-    for c in s.chars().take(length) {
+    // Escape sequences are kept when the content is cut, so that colors are still reset
+    let mut pushed = 0;
+    let mut in_escape = false;
+    for c in s.chars() {
+        if is_escape_start(c) {
+            in_escape = true;
+        } else if in_escape {
+            in_escape = !c.is_ascii_alphabetic();
+        } else if pushed == length {
+            continue;
+        } else {
+            pushed += 1;
+        }
         output.push(c);
     }
     push_char(output, ' ', right_pad);
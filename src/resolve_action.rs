@@ -1,50 +1,110 @@
+use serde::{Deserialize, Serialize};
+
 use crate::{
     apply_area_effects::apply_area_effects,
+    board::Board,
     data_model::{ActionMovement, ActionOnSelf, ActionTargeted, Character},
+    game_event::{GameEvent, Observer, RecordedInput},
     hex_grid::PosAxial,
-    movement::perform_movement_unchecked,
+    movement::{MovementError, check_movement, perform_movement_unchecked},
+    registry::Registry,
+    targeting::{TargetError, check_target},
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionInputOnSelf {}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionInputTargeted {
     pub target: PosAxial,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct ActionInputMovement {
     pub path: Vec<PosAxial>,
 }
 
-pub fn resolve_action_movement(
+pub fn resolve_action_movement<C>(
     action: &ActionMovement,
     input: &ActionInputMovement,
+    board: &Board,
     character: &mut Character,
-) {
-    let _ = action;
-    perform_movement_unchecked(character, input.path.iter());
+    characters: &mut C,
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<(), MovementError>
+where
+    for<'c> &'c mut C: IntoIterator<Item = &'c mut Character>,
+{
+    let occupied = characters
+        .into_iter()
+        .filter(|character| character.is_alive())
+        .map(|character| character.pos.clone())
+        .collect();
+    check_movement(action, board, character, &occupied, &input.path)?;
+    observer.on_event(&GameEvent::Input(RecordedInput::Movement(input.clone())));
+    perform_movement_unchecked(
+        board,
+        character,
+        input.path.iter(),
+        action.jump,
+        registry,
+        observer,
+    );
+    Ok(())
 }
 
 pub fn resolve_action_targeted<C>(
     action: &ActionTargeted,
     input: &ActionInputTargeted,
+    board: &Board,
     character: &mut Character,
     characters: &mut C,
-) where
+    registry: &Registry,
+    observer: &mut dyn Observer,
+) -> Result<(), TargetError>
+where
     for<'c> &'c mut C: IntoIterator<Item = &'c mut Character>,
 {
-    apply_area_effects(action.effects.iter(), &input.target, characters, character);
+    {
+        let characters: Vec<&Character> = characters
+            .into_iter()
+            .map(|c| &*c)
+            .filter(|c| c.is_alive())
+            .collect();
+        check_target(action, board, character, &characters, &input.target)?;
+    }
+    observer.on_event(&GameEvent::Input(RecordedInput::Targeted(input.clone())));
+    apply_area_effects(
+        action.effects.iter(),
+        &input.target,
+        board,
+        characters,
+        character,
+        registry,
+        observer,
+    );
+    Ok(())
 }
 
 pub fn resolve_action_on_self<C>(
     action: &ActionOnSelf,
+    board: &Board,
     character: &mut Character,
     characters: &mut C,
+    registry: &Registry,
+    observer: &mut dyn Observer,
 ) where
     for<'c> &'c mut C: IntoIterator<Item = &'c mut Character>,
 {
     let target = character.pos.clone();
-    apply_area_effects(action.effects.iter(), &target, characters, character);
+    apply_area_effects(
+        action.effects.iter(),
+        &target,
+        board,
+        characters,
+        character,
+        registry,
+        observer,
+    );
 }
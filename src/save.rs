@@ -0,0 +1,101 @@
+#![allow(dead_code)]
+use std::{fmt, path::Path};
+
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::encounter::Encounter;
+
+// Bump when the format changes in a way older saves can not be read with.
+pub const SAVE_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct SaveFile<'a> {
+    version: u32,
+    encounter: &'a Encounter,
+}
+
+#[derive(Deserialize)]
+struct LoadFile {
+    encounter: Encounter,
+}
+
+// Read first, so that saves from other versions are rejected with a clear error instead of
+// whatever the parser happens to stumble over.
+#[derive(Deserialize)]
+struct SaveHeader {
+    version: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SaveError {
+    Io(String),
+    UnsupportedVersion {
+        found: u32,
+        supported: u32,
+    },
+    Serialize(String),
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+}
+
+impl fmt::Display for SaveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SaveError::Io(message) | SaveError::Serialize(message) => write!(f, "{}", message),
+            SaveError::UnsupportedVersion { found, supported } => write!(
+                f,
+                "Save file version {} is not supported, expected version {}",
+                found, supported
+            ),
+            SaveError::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "{}:{}: {}", line, column, message),
+        }
+    }
+}
+
+impl From<ron::error::SpannedError> for SaveError {
+    fn from(err: ron::error::SpannedError) -> Self {
+        SaveError::Parse {
+            line: err.span.start.line,
+            column: err.span.start.col,
+            message: err.code.to_string(),
+        }
+    }
+}
+
+pub fn save_encounter(encounter: &Encounter) -> Result<String, SaveError> {
+    let save_file = SaveFile {
+        version: SAVE_VERSION,
+        encounter,
+    };
+    ron::ser::to_string_pretty(&save_file, PrettyConfig::new())
+        .map_err(|err| SaveError::Serialize(err.to_string()))
+}
+
+pub fn load_encounter(source: &str) -> Result<Encounter, SaveError> {
+    let header: SaveHeader = ron::from_str(source)?;
+    if header.version != SAVE_VERSION {
+        return Err(SaveError::UnsupportedVersion {
+            found: header.version,
+            supported: SAVE_VERSION,
+        });
+    }
+    let load_file: LoadFile = ron::from_str(source)?;
+    Ok(load_file.encounter)
+}
+
+pub fn save_encounter_to_file(encounter: &Encounter, path: &Path) -> Result<(), SaveError> {
+    std::fs::write(path, save_encounter(encounter)?).map_err(|err| SaveError::Io(err.to_string()))
+}
+
+pub fn load_encounter_from_file(path: &Path) -> Result<Encounter, SaveError> {
+    let source = std::fs::read_to_string(path).map_err(|err| SaveError::Io(err.to_string()))?;
+    load_encounter(&source)
+}
@@ -0,0 +1,242 @@
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    data_model::{CharacterId, CharacterTeam},
+    encounter::{Encounter, EncounterOutcome},
+    game_event::GameEvent,
+    monster_ai::{MonsterAi, Policy},
+    play::{CardCost, card_cost},
+    play_state::{DuplicateCharacterId, start_encounter, step_play_state},
+    registry::Registry,
+};
+
+#[derive(Debug, Clone)]
+pub struct SimulationConfig {
+    pub encounters: usize,
+    pub seed: u64, // Of the first encounter, the others count up from it.
+    pub player_policy: Policy,
+    pub monster_policy: Policy, // Only used by monsters without a type, the others play their ability cards.
+    pub max_rounds: usize, // Encounters still in progress after this many rounds are unfinished.
+}
+
+impl Default for SimulationConfig {
+    fn default() -> Self {
+        Self {
+            encounters: 100,
+            seed: 0,
+            player_policy: Policy::Aggressive,
+            monster_policy: Policy::Aggressive,
+            max_rounds: 50,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SimulationError {
+    NoCharacters,
+    DuplicateCharacterId(DuplicateCharacterId),
+}
+
+impl From<DuplicateCharacterId> for SimulationError {
+    fn from(err: DuplicateCharacterId) -> Self {
+        SimulationError::DuplicateCharacterId(err)
+    }
+}
+
+impl fmt::Display for SimulationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SimulationError::NoCharacters => write!(f, "There are no characters to simulate"),
+            SimulationError::DuplicateCharacterId(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+// Totals over every simulated encounter.
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct SimulationReport {
+    pub encounters: usize,
+    pub players_won: usize,
+    pub monsters_won: usize,
+    pub unfinished: usize, // Out of rounds, or ended by a custom objective.
+    pub stuck: usize,      // Ended by an input of the AI that the engine rejected.
+    pub rounds: usize,
+    pub damage_to_monsters: usize,
+    pub damage_to_players: usize,
+    pub stamina_spent: usize, // On cards played by players, not counting cards paid with health.
+    pub player_turns: usize,  // Including lost ones.
+}
+
+fn ratio(numerator: usize, denominator: usize) -> f64 {
+    if denominator == 0 {
+        0.0
+    } else {
+        numerator as f64 / denominator as f64
+    }
+}
+
+impl SimulationReport {
+    pub fn win_rate(&self) -> f64 {
+        ratio(self.players_won, self.encounters)
+    }
+    pub fn average_rounds(&self) -> f64 {
+        ratio(self.rounds, self.encounters)
+    }
+    pub fn damage_to_monsters_per_round(&self) -> f64 {
+        ratio(self.damage_to_monsters, self.rounds)
+    }
+    pub fn damage_to_players_per_round(&self) -> f64 {
+        ratio(self.damage_to_players, self.rounds)
+    }
+    pub fn stamina_per_player_turn(&self) -> f64 {
+        ratio(self.stamina_spent, self.player_turns)
+    }
+}
+
+impl fmt::Display for SimulationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Encounters:                  {}", self.encounters)?;
+        writeln!(
+            f,
+            "Win rate:                    {:.1}% ({} won, {} lost, {} unfinished, {} stuck)",
+            100.0 * self.win_rate(),
+            self.players_won,
+            self.monsters_won,
+            self.unfinished,
+            self.stuck
+        )?;
+        writeln!(
+            f,
+            "Average rounds:              {:.2}",
+            self.average_rounds()
+        )?;
+        writeln!(
+            f,
+            "Damage to monsters / round:  {:.2}",
+            self.damage_to_monsters_per_round()
+        )?;
+        writeln!(
+            f,
+            "Damage to players / round:   {:.2}",
+            self.damage_to_players_per_round()
+        )?;
+        write!(
+            f,
+            "Stamina / player turn:       {:.2}",
+            self.stamina_per_player_turn()
+        )
+    }
+}
+
+// Plays copies of the encounter to the end without any input, each with its own seed, and both
+// teams controlled by the AI.
+pub fn simulate(
+    template: &Encounter,
+    config: &SimulationConfig,
+    registry: &Registry,
+) -> Result<SimulationReport, SimulationError> {
+    if template.characters.is_empty() {
+        return Err(SimulationError::NoCharacters);
+    }
+    let mut report = SimulationReport::default();
+    for index in 0..config.encounters {
+        let seed = config.seed.wrapping_add(index as u64);
+        simulate_encounter(template, config, seed, registry, &mut report)?;
+    }
+    Ok(report)
+}
+
+fn simulate_encounter(
+    template: &Encounter,
+    config: &SimulationConfig,
+    seed: u64,
+    registry: &Registry,
+    report: &mut SimulationReport,
+) -> Result<(), DuplicateCharacterId> {
+    let mut encounter = template.clone();
+    encounter.play_state = encounter.play_state.with_seed(seed);
+    let teams: HashMap<CharacterId, CharacterTeam> = encounter
+        .characters
+        .iter()
+        .map(|character| (character.id, character.team.clone()))
+        .collect();
+    let is_player = |id: &CharacterId| teams.get(id) == Some(&CharacterTeam::Player);
+    let mut player_ai = MonsterAi::new(config.player_policy, registry);
+    let mut monster_ai = MonsterAi::new(config.monster_policy, registry);
+    let mut rounds = 0;
+    let mut stuck = false;
+
+    start_encounter(&mut encounter.play_state, &mut encounter.characters)?;
+    while *encounter.play_state.outcome() == EncounterOutcome::InProgress
+        && rounds <= config.max_rounds
+        && !stuck
+    {
+        let mut events = Vec::new();
+        let observer = &mut |event: &GameEvent| events.push(event.clone());
+        let acting = encounter.play_state.acting();
+        let ai = match encounter.characters[acting].team {
+            CharacterTeam::Player => &mut player_ai,
+            CharacterTeam::Monster => &mut monster_ai,
+        };
+        ai.update(&encounter.board, &encounter.characters, acting);
+        // The AI only chooses inputs the engine accepts, a rejected one would be asked for again
+        stuck = step_play_state(
+            &mut encounter.play_state,
+            ai,
+            &encounter.board,
+            &mut encounter.characters,
+            registry,
+            observer,
+        )
+        .is_err();
+        for event in &events {
+            match event {
+                GameEvent::RoundBegan { .. } => rounds += 1,
+                GameEvent::DamageDealt { target, amount, .. } if is_player(target) => {
+                    report.damage_to_players += amount;
+                }
+                GameEvent::DamageDealt { amount, .. } => report.damage_to_monsters += amount,
+                GameEvent::CardPlayed { character, card } if is_player(character) => {
+                    // Charged like in play_card, items do not change during an encounter
+                    let player = encounter.characters.iter().find(|c| c.id == *character);
+                    if let (Some(player), Some(card_data)) = (player, registry.card(card))
+                        && let CardCost::Stamina(cost) = card_cost(player, card_data, registry)
+                    {
+                        report.stamina_spent += cost;
+                    }
+                }
+                GameEvent::TurnBegan { character } if is_player(character) => {
+                    report.player_turns += 1;
+                }
+                _ => {}
+            }
+        }
+    }
+
+    report.encounters += 1;
+    report.rounds += rounds.min(config.max_rounds);
+    match encounter.play_state.outcome() {
+        _ if stuck => report.stuck += 1,
+        EncounterOutcome::PlayersWon => report.players_won += 1,
+        EncounterOutcome::MonstersWon => report.monsters_won += 1,
+        _ => report.unfinished += 1,
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_simulate_without_characters() {
+        let config = SimulationConfig {
+            encounters: 3,
+            ..Default::default()
+        };
+        assert_eq!(
+            simulate(&Encounter::default(), &config, &Registry::builtin()),
+            Err(SimulationError::NoCharacters)
+        );
+    }
+}
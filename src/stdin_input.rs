@@ -0,0 +1,355 @@
+use std::{
+    io::{BufRead, IsTerminal},
+    path::{Path, PathBuf},
+};
+
+use clap::Parser;
+
+use crate::{
+    COMMAND_NAME,
+    board_svg::export,
+    board_view::{BoardView, character_name},
+    data_model::{Ability, AbilityKind, ActionMovement, ActionOnSelf, ActionTargeted},
+    encounter::{Encounter, EncounterOutcome},
+    game_event::GameEvent,
+    game_loop::FrontEnd,
+    hex_grid::{PosAxial, PosOddQHex},
+    play_state::{AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn},
+    registry::Registry,
+    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+};
+
+#[derive(clap_derive::Parser, Debug)]
+#[command(name = COMMAND_NAME, no_binary_name = true)]
+struct GameCli {
+    #[command(subcommand)]
+    command: GameCommand,
+}
+
+// Hexes are given as "r q" in the odd-q coordinates shown on the board.
+#[derive(clap_derive::Subcommand, Debug)]
+enum GameCommand {
+    Play {
+        index: usize, // Into the hand
+    },
+    EndTurn,
+    Target {
+        r: isize,
+        q: isize,
+    },
+    Move {
+        #[arg(allow_negative_numbers = true)]
+        path: Vec<isize>, // "r q" of every hex along the path
+    },
+    Confirm,
+    Cancel,
+    Use,
+    Decline,
+    Choose {
+        option: usize,
+    },
+    Export {
+        path: PathBuf, // SVG, or PNG if it ends in .png
+    },
+    Quit,
+}
+
+// Reads one command from stdin every time it is polled. Anything that is not an answer to the poll
+// is reported and the poll stays pending, so it is asked again on the next step.
+#[derive(Default)]
+pub struct StdinInput {
+    encounter: Encounter, // As of the last show, for exports.
+    quit: bool,           // Quit was entered, or stdin was closed.
+}
+
+impl StdinInput {
+    fn poll<T>(
+        &mut self,
+        prompt: &str,
+        f: impl FnOnce(GameCommand) -> Result<T, String>,
+    ) -> PendingInput<T> {
+        println!("{}", prompt);
+        let mut line = String::new();
+        match std::io::stdin().lock().read_line(&mut line) {
+            Ok(0) | Err(_) => {
+                self.quit = true;
+                return PendingInput::Pending;
+            }
+            Ok(_) => {}
+        }
+        match GameCli::try_parse_from(line.split_whitespace()) {
+            Ok(GameCli {
+                command: GameCommand::Quit,
+            }) => {
+                self.quit = true;
+                PendingInput::Pending
+            }
+            Ok(GameCli {
+                command: GameCommand::Export { path },
+            }) => {
+                export_and_report(&BoardView::of_encounter(&self.encounter), &path);
+                PendingInput::Pending
+            }
+            Ok(cli) => match f(cli.command) {
+                Ok(input) => PendingInput::Some(input),
+                Err(err) => {
+                    println!("{}", err);
+                    PendingInput::Pending
+                }
+            },
+            Err(err) => {
+                println!("{}", err);
+                PendingInput::Pending
+            }
+        }
+    }
+
+    fn poll_cancelable<T>(
+        &mut self,
+        prompt: &str,
+        f: impl FnOnce(GameCommand) -> Result<T, String>,
+    ) -> PendingInput<Cancelable<T>> {
+        self.poll(
+            &format!("{} Or cancel the ability (cancel).", prompt),
+            |command| match command {
+                GameCommand::Cancel => Ok(Cancelable::Canceled),
+                command => f(command).map(Cancelable::Some),
+            },
+        )
+    }
+}
+
+const TARGETED_PROMPT: &str = "Choose a target (target <r> <q>).";
+const MOVEMENT_PROMPT: &str = "Choose a path (move <r> <q> <r> <q> ...), or an empty one to stay.";
+const ON_SELF_PROMPT: &str = "Use the ability (confirm).";
+
+fn targeted_input(command: GameCommand) -> Result<ActionInputTargeted, String> {
+    match command {
+        GameCommand::Target { r, q } => Ok(ActionInputTargeted {
+            target: PosOddQHex { r, q }.to_axial(),
+        }),
+        command => Err(format!("Expected a target, not {:?}", command)),
+    }
+}
+
+fn movement_input(command: GameCommand) -> Result<ActionInputMovement, String> {
+    match command {
+        GameCommand::Move { path } if path.len() % 2 == 0 => Ok(ActionInputMovement {
+            path: path
+                .chunks(2)
+                .map(|hex| PosOddQHex::new(hex[0], hex[1]).to_axial())
+                .collect::<Vec<PosAxial>>(),
+        }),
+        GameCommand::Move { .. } => Err("Every hex of the path needs both r and q".into()),
+        command => Err(format!("Expected a path, not {:?}", command)),
+    }
+}
+
+fn on_self_input(command: GameCommand) -> Result<ActionInputOnSelf, String> {
+    match command {
+        GameCommand::Confirm => Ok(ActionInputOnSelf {}),
+        command => Err(format!("Expected confirm, not {:?}", command)),
+    }
+}
+
+impl Input for StdinInput {
+    // Nothing to choose
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Some(ActionInputOnSelf {})
+    }
+    fn poll_action_input_targeted(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        self.poll(TARGETED_PROMPT, targeted_input)
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        self.poll(MOVEMENT_PROMPT, movement_input)
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        _: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        self.poll_cancelable(ON_SELF_PROMPT, on_self_input)
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        self.poll_cancelable(TARGETED_PROMPT, targeted_input)
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        self.poll_cancelable(MOVEMENT_PROMPT, movement_input)
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        self.poll(
+            "Play a card (play <index>) or end the turn (end-turn).",
+            |command| match command {
+                GameCommand::Play { index } => Ok(PlayCardOrEndTurn::PlayCard(index)),
+                GameCommand::EndTurn => Ok(PlayCardOrEndTurn::EndTurn),
+                command => Err(format!("Expected a card or end-turn, not {:?}", command)),
+            },
+        )
+    }
+    fn poll_ability_choice(&mut self, ability: &Ability) -> PendingInput<AbilityChoice> {
+        let prompt = match &ability.kind {
+            AbilityKind::ChooseOne(options) => {
+                format!("Choose one of {} options (choose <option>).", options.len())
+            }
+            _ => "Use the optional ability (use) or skip it (decline).".into(),
+        };
+        self.poll(&prompt, |command| match command {
+            GameCommand::Use => Ok(AbilityChoice::Use),
+            GameCommand::Decline => Ok(AbilityChoice::Decline),
+            GameCommand::Choose { option } => Ok(AbilityChoice::Choose(option)),
+            command => Err(format!("Expected a choice, not {:?}", command)),
+        })
+    }
+}
+
+impl FrontEnd for StdinInput {
+    fn quit(&self) -> bool {
+        self.quit
+    }
+    fn show(&mut self, encounter: &Encounter, registry: &Registry, events: &[GameEvent]) {
+        for event in events {
+            println!("{:?}", event);
+        }
+        print_encounter(encounter, registry);
+        self.encounter = encounter.clone();
+    }
+    fn show_error(&mut self, message: &str) {
+        println!("{}", message);
+    }
+    fn show_outcome(&mut self, outcome: &EncounterOutcome) {
+        println!("{:?}", outcome);
+    }
+}
+
+pub fn export_and_report(board_view: &BoardView, path: &Path) {
+    match export(board_view, path) {
+        Ok(()) => println!("Exported the board to {}", path.display()),
+        Err(err) => println!("{}", err),
+    }
+}
+
+pub fn print_encounter(encounter: &Encounter, registry: &Registry) {
+    let board_view = BoardView::of_encounter(encounter)
+        .with_colors(std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none());
+    println!("{}", board_view.render());
+    let play_state = &encounter.play_state;
+    // Nothing to tell before the first step
+    if !play_state.turn_order().is_empty() {
+        println!(
+            "Round {}, turn {}, {:?} phase",
+            play_state.round() + 1,
+            play_state.turn() + 1,
+            play_state.phase()
+        );
+        if !play_state.trigger_stack().is_empty() {
+            println!(
+                "{} triggered abilities waiting",
+                play_state.trigger_stack().len()
+            );
+        }
+    }
+    for character in encounter.characters.iter().filter(|c| c.is_alive()) {
+        let pos = character.pos.to_odd_q();
+        let conditions: String = character
+            .conditions
+            .active()
+            .iter()
+            .map(|(condition, value)| format!(" {:?}({})", condition, value))
+            .collect();
+        println!(
+            "{} at {} {}: {}/{} health, {}/{} stamina{}",
+            character_name(character),
+            pos.r,
+            pos.q,
+            character.health_current,
+            character.health_max,
+            character.stamina_current,
+            character.stamina_max,
+            conditions
+        );
+    }
+    if let Some(character) = encounter
+        .characters
+        .get(encounter.play_state.has_turn())
+        .filter(|character| !character.deck.hand.is_empty())
+    {
+        let hand: Vec<String> = character
+            .deck
+            .hand
+            .iter()
+            .enumerate()
+            .map(|(index, card)| match registry.card(card) {
+                Some(card_data) => format!("{}: {} ({})", index, card, card_data.stamina_cost),
+                None => format!("{}: {} (unknown)", index, card),
+            })
+            .collect();
+        println!("Hand of {}: {}", character_name(character), hand.join(", "));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hex_grid::distance;
+
+    fn command(line: &str) -> GameCommand {
+        GameCli::try_parse_from(line.split_whitespace())
+            .unwrap()
+            .command
+    }
+
+    #[test]
+    pub fn test_odd_q_coordinates() {
+        // Odd columns are shifted down by half a hex
+        assert_eq!(PosOddQHex::new(0, 0).to_axial(), PosAxial::new(0, 0));
+        assert_eq!(PosOddQHex::new(0, 1).to_axial(), PosAxial::new(0, 1));
+        assert_eq!(PosOddQHex::new(1, 2).to_axial(), PosAxial::new(0, 2));
+        assert_eq!(PosOddQHex::new(0, -1).to_axial(), PosAxial::new(1, -1));
+        assert_eq!(PosAxial::new(-1, 3).to_odd_q(), PosOddQHex::new(0, 3));
+    }
+
+    #[test]
+    pub fn test_targeted_input() {
+        assert_eq!(
+            targeted_input(command("target 1 2")),
+            Ok(ActionInputTargeted {
+                target: PosAxial::new(0, 2)
+            })
+        );
+        assert!(targeted_input(command("confirm")).is_err());
+    }
+
+    #[test]
+    pub fn test_movement_input() {
+        let path = movement_input(command("move 0 0 0 1 1 2 -1 -1"))
+            .unwrap()
+            .path;
+        assert_eq!(
+            path,
+            vec![
+                PosAxial::new(0, 0),
+                PosAxial::new(0, 1),
+                PosAxial::new(0, 2),
+                PosAxial::new(0, -1),
+            ]
+        );
+        // Consecutive hexes of a row are adjacent, whatever the parity of the column
+        assert_eq!(distance(&path[0], &path[1]), 1);
+        assert_eq!(distance(&path[1], &path[2]), 1);
+
+        assert_eq!(movement_input(command("move")).unwrap().path, Vec::new());
+        assert!(movement_input(command("move 1 2 3")).is_err());
+        assert!(movement_input(command("target 1 2")).is_err());
+    }
+}
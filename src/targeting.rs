@@ -0,0 +1,130 @@
+use std::{collections::HashSet, fmt};
+
+use crate::{
+    board::Board,
+    character_filter::filter_character,
+    data_model::{ActionTargeted, Character, Condition, Reach},
+    hex_grid::{PosAxial, distance, hexes_within, pos_in_area},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TargetError {
+    NotOnBoard { target: PosAxial },
+    NotAdjacent { target: PosAxial },
+    OutOfRange { target: PosAxial, range: usize },
+    NoLineOfSight { target: PosAxial },
+    Invisible { target: PosAxial },
+    FilteredOut { target: PosAxial },
+}
+
+impl fmt::Display for TargetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TargetError::NotOnBoard { target } => {
+                write!(f, "{:?} is not on the board", target)
+            }
+            TargetError::NotAdjacent { target } => {
+                write!(f, "{:?} is not adjacent, and the action is melee", target)
+            }
+            TargetError::OutOfRange { target, range } => {
+                write!(f, "{:?} is further away than range {}", target, range)
+            }
+            TargetError::NoLineOfSight { target } => {
+                write!(f, "{:?} is not in line of sight", target)
+            }
+            TargetError::Invisible { target } => {
+                write!(f, "The character at {:?} is Invisible", target)
+            }
+            TargetError::FilteredOut { target } => {
+                write!(
+                    f,
+                    "The character at {:?} can not be targeted by the action",
+                    target
+                )
+            }
+        }
+    }
+}
+
+pub fn reach_distance(reach: &Reach) -> usize {
+    match reach {
+        Reach::Melee => 1,
+        Reach::Ranged { range } => *range,
+    }
+}
+
+// Checks that the target hex may be chosen for the action. The target need not contain a character,
+// but if it does, the character must be targetable: not Invisible, and passing the filters of the
+// effects that hit the target hex itself.
+pub fn check_target(
+    action: &ActionTargeted,
+    board: &Board,
+    character: &Character,
+    characters: &[&Character],
+    target: &PosAxial,
+) -> Result<(), TargetError> {
+    if !board.contains(target) {
+        return Err(TargetError::NotOnBoard {
+            target: target.clone(),
+        });
+    }
+    match action.reach {
+        Reach::Melee => {
+            if distance(&character.pos, target) != 1 {
+                return Err(TargetError::NotAdjacent {
+                    target: target.clone(),
+                });
+            }
+        }
+        Reach::Ranged { range } => {
+            if distance(&character.pos, target) > range {
+                return Err(TargetError::OutOfRange {
+                    target: target.clone(),
+                    range,
+                });
+            }
+            let occupied: HashSet<_> = characters
+                .iter()
+                .map(|character| character.pos.clone())
+                .collect();
+            if !board.has_line_of_sight(&character.pos, target, &occupied) {
+                return Err(TargetError::NoLineOfSight {
+                    target: target.clone(),
+                });
+            }
+        }
+    }
+    if let Some(target_character) = characters.iter().find(|other| other.pos == *target) {
+        if target_character.conditions.has(&Condition::Invisible) {
+            return Err(TargetError::Invisible {
+                target: target.clone(),
+            });
+        }
+        let filtered_out = action
+            .effects
+            .iter()
+            .filter(|area_effect| pos_in_area(target, &area_effect.area, target))
+            .filter_map(|area_effect| area_effect.filter.as_ref())
+            .any(|filter| !filter_character(target_character, filter, character));
+        if filtered_out {
+            return Err(TargetError::FilteredOut {
+                target: target.clone(),
+            });
+        }
+    }
+    Ok(())
+}
+
+// Every hex that passes check_target, in sorted order.
+pub fn legal_targets(
+    action: &ActionTargeted,
+    board: &Board,
+    character: &Character,
+    characters: &[&Character],
+) -> Vec<PosAxial> {
+    let mut targets: Vec<_> = hexes_within(&character.pos, reach_distance(&action.reach))
+        .filter(|target| check_target(action, board, character, characters, target).is_ok())
+        .collect();
+    targets.sort();
+    targets
+}
@@ -1,12 +1,43 @@
+mod test_card_brawl;
+mod test_card_corpse_smash;
+mod test_card_plague_shot;
 mod test_card_preparation;
 mod test_card_rain_of_arrows;
+mod test_card_shove;
+mod test_card_steady_shot;
+mod test_engine_ability_choice;
+mod test_engine_board;
+mod test_engine_board_svg;
+mod test_engine_board_view;
+mod test_engine_card_costs;
+mod test_engine_deck;
+mod test_engine_encounter_outcome;
+mod test_engine_event_log;
+mod test_engine_game_loop;
+mod test_engine_initiative;
+mod test_engine_monster_abilities;
+mod test_engine_monster_ai;
+mod test_engine_movement_validation;
+mod test_engine_observer;
+mod test_engine_registry;
+mod test_engine_resolution_order;
+mod test_engine_save;
+mod test_engine_simulation;
+mod test_engine_targeting;
+mod test_engine_trigger_stack;
+mod test_engine_turn_phases;
 mod test_engine_turn_stat_attack_actions;
 mod test_engine_turn_stat_moved_tiles;
+mod test_item_bloodletters_ring;
+mod test_item_boodbound_harness;
 mod test_item_chestplate_of_the_enraged;
 mod test_item_cloak_of_invisibility;
 mod test_item_monks_robe;
 mod test_item_shroud_of_the_poison_feeder;
 mod test_item_stillroot_plate;
 mod test_item_thorngrown_vest;
+mod test_item_volatile_core;
+mod test_mechanic_conditions;
+mod test_mechanic_death;
 mod test_mechanic_fury;
 mod tools;
@@ -0,0 +1,50 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    cards::Card,
+    data_model::{Character, CharacterTeam, Condition},
+    hex_grid::PosAxial,
+    test::tools::play_card_with_inputs,
+};
+
+#[test]
+pub fn test_card_brawl() {
+    let character = &mut Character {
+        team: CharacterTeam::Player,
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let mut characters: Vec<_> = [
+        (CharacterTeam::Monster, (0, 2)),
+        (CharacterTeam::Monster, (-2, 0)),
+        (CharacterTeam::Monster, (1, -1)),
+        (CharacterTeam::Monster, (0, -3)),
+        (CharacterTeam::Player, (2, 0)),
+    ]
+    .iter()
+    .map(|(team, (r, q))| Character {
+        team: team.clone(),
+        pos: PosAxial::new(*r, *q),
+        ..Default::default()
+    })
+    .collect();
+
+    play_card_with_inputs(Card::Brawl, character, &mut characters, iter::empty()).unwrap();
+
+    // Enemies within 2 hexes are pulled in and then weakened
+    assert_eq!(characters[0].pos, PosAxial::new(0, 1));
+    assert_eq!(characters[1].pos, PosAxial::new(-1, 0));
+    assert_eq!(characters[0].conditions.get(&Condition::Weak), 1);
+    assert_eq!(characters[1].conditions.get(&Condition::Weak), 1);
+
+    // Already adjacent enemies are only weakened
+    assert_eq!(characters[2].pos, PosAxial::new(1, -1));
+    assert_eq!(characters[2].conditions.get(&Condition::Weak), 1);
+
+    // Enemies further away and allies are not affected
+    assert_eq!(characters[3].pos, PosAxial::new(0, -3));
+    assert_eq!(characters[3].conditions.get(&Condition::Weak), 0);
+    assert_eq!(characters[4].pos, PosAxial::new(2, 0));
+    assert_eq!(characters[4].conditions.get(&Condition::Weak), 0);
+}
@@ -0,0 +1,45 @@
+#![cfg(test)]
+use crate::{
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    test::tools::{play_card_with_inputs, single_targeted_input},
+};
+
+#[test]
+pub fn test_card_corpse_smash() {
+    let character = &mut Character {
+        health_current: 10,
+        health_max: 10,
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let target = PosAxial::new(0, 1);
+    let other_characters = &mut vec![
+        Character {
+            pos: target.clone(),
+            health_current: 1,
+            health_max: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 2),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+    ];
+
+    play_card_with_inputs(
+        Card::CorpseSmash,
+        character,
+        other_characters,
+        single_targeted_input(target).iter(),
+    )
+    .unwrap();
+
+    // 3 damage against 1 health leaves 2 overkill for everyone adjacent to the target
+    assert!(!other_characters[0].is_alive());
+    assert_eq!(other_characters[1].health_current, 8);
+    assert_eq!(character.health_current, 8);
+}
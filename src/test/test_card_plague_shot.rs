@@ -0,0 +1,58 @@
+#![cfg(test)]
+use crate::{
+    cards::Card,
+    data_model::{Character, Condition},
+    hex_grid::PosAxial,
+    test::tools::{play_card_with_inputs, single_targeted_input},
+};
+
+#[test]
+pub fn test_card_plague_shot() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let target = PosAxial::new(0, 2);
+    let other_characters = &mut vec![
+        Character {
+            pos: target.clone(),
+            health_current: 4,
+            health_max: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 3),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 4),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+    ];
+
+    // The first shot does not kill the target, so nothing spreads
+    play_card_with_inputs(
+        Card::PlagueShot,
+        character,
+        other_characters,
+        single_targeted_input(target.clone()).iter(),
+    )
+    .unwrap();
+    assert_eq!(other_characters[1].conditions.get(&Condition::Poison), 0);
+
+    play_card_with_inputs(
+        Card::PlagueShot,
+        character,
+        other_characters,
+        single_targeted_input(target).iter(),
+    )
+    .unwrap();
+    assert!(!other_characters[0].is_alive());
+    assert_eq!(other_characters[0].conditions.get(&Condition::Poison), 0);
+    assert_eq!(other_characters[1].conditions.get(&Condition::Poison), 2);
+    assert_eq!(other_characters[2].conditions.get(&Condition::Poison), 0);
+}
@@ -0,0 +1,78 @@
+#![cfg(test)]
+use crate::{
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    test::tools::{
+        ActionInput, play_card_with_inputs, single_movement_input, single_targeted_input,
+    },
+    turn_stats::TurnStat,
+};
+
+fn shove_inputs() -> Vec<ActionInput> {
+    [
+        single_movement_input(vec![PosAxial::new(0, 1)]),
+        single_targeted_input(PosAxial::new(0, 2)),
+    ]
+    .concat()
+}
+
+#[test]
+pub fn test_card_shove() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut [Character {
+        pos: PosAxial::new(0, 2),
+        ..Default::default()
+    }];
+
+    play_card_with_inputs(
+        Card::Shove,
+        character,
+        other_characters,
+        shove_inputs().iter(),
+    )
+    .unwrap();
+
+    assert_eq!(character.pos, PosAxial::new(0, 1));
+    assert_eq!(other_characters[0].pos, PosAxial::new(0, 4));
+    assert_eq!(
+        other_characters[0]
+            .turn_stats
+            .get(0, &TurnStat::SpacesMovedForced),
+        2
+    );
+    assert_eq!(
+        other_characters[0]
+            .turn_stats
+            .get(0, &TurnStat::SpacesMoved),
+        0
+    );
+}
+
+#[test]
+pub fn test_card_shove_blocked() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let mut other_characters: Vec<_> = [(0, 2), (0, 3), (-1, 3), (1, 2)]
+        .iter()
+        .map(|(r, q)| Character {
+            pos: PosAxial::new(*r, *q),
+            ..Default::default()
+        })
+        .collect();
+
+    play_card_with_inputs(
+        Card::Shove,
+        character,
+        &mut other_characters,
+        shove_inputs().iter(),
+    )
+    .unwrap();
+
+    assert_eq!(other_characters[0].pos, PosAxial::new(0, 2));
+}
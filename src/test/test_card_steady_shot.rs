@@ -0,0 +1,83 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    targeting::TargetError,
+    test::tools::{TestSetupError, play_card_with_inputs_on_board, single_targeted_input},
+};
+
+const HEALTH_STARTING: usize = 10;
+
+fn shoot(
+    board: &Board,
+    target_pos: PosAxial,
+    other_characters: &mut Vec<Character>,
+) -> Result<(), TestSetupError> {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    play_card_with_inputs_on_board(
+        Card::SteadyShot,
+        board,
+        character,
+        other_characters,
+        single_targeted_input(target_pos).iter(),
+    )
+}
+
+fn target_at(pos: &PosAxial) -> Character {
+    Character {
+        pos: pos.clone(),
+        health_current: HEALTH_STARTING,
+        ..Default::default()
+    }
+}
+
+#[test]
+pub fn test_card_steady_shot_range() {
+    let in_range = PosAxial::new(-3, 3);
+    let out_of_range = PosAxial::new(0, 4);
+    let characters = &mut vec![target_at(&in_range), target_at(&out_of_range)];
+
+    shoot(&Board::default(), in_range, characters).unwrap();
+    assert!(matches!(
+        shoot(&Board::default(), out_of_range, characters),
+        Err(TestSetupError::InvalidTarget(TargetError::OutOfRange {
+            range: 3,
+            ..
+        }))
+    ));
+
+    assert!(characters[0].health_current < HEALTH_STARTING);
+    assert_eq!(characters[1].health_current, HEALTH_STARTING);
+}
+
+#[test]
+pub fn test_card_steady_shot_line_of_sight() {
+    let target_pos = PosAxial::new(0, 3);
+    let characters = &mut vec![target_at(&target_pos), target_at(&PosAxial::new(0, 1))];
+    let walled = Board::default().with_terrain(PosAxial::new(0, 2), Terrain::Wall);
+
+    assert!(matches!(
+        shoot(&walled, target_pos.clone(), characters),
+        Err(TestSetupError::InvalidTarget(
+            TargetError::NoLineOfSight { .. }
+        ))
+    ));
+    assert_eq!(characters[0].health_current, HEALTH_STARTING);
+
+    let characters_block = Board::default().with_characters_blocking_line_of_sight(true);
+    assert!(matches!(
+        shoot(&characters_block, target_pos.clone(), characters),
+        Err(TestSetupError::InvalidTarget(
+            TargetError::NoLineOfSight { .. }
+        ))
+    ));
+    assert_eq!(characters[0].health_current, HEALTH_STARTING);
+
+    shoot(&Board::default(), target_pos, characters).unwrap();
+    assert!(characters[0].health_current < HEALTH_STARTING);
+}
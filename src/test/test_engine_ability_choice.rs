@@ -0,0 +1,107 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition},
+    deck::Deck,
+    event_log::EventLog,
+    game_event::{GameEvent, RecordedInput},
+    hex_grid::PosAxial,
+    play_state::{AbilityChoice, PlayState, StepError},
+    test::tools::{ActionInput, ScriptedInput, ScriptedInputEntry, run_script_with_observer},
+};
+
+fn characters() -> [Character; 2] {
+    [
+        Character {
+            id: CharacterId(0),
+            team: CharacterTeam::Player,
+            pos: PosAxial::new(1, 1),
+            health_current: 10,
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Guard]),
+            ..Default::default()
+        },
+        Character {
+            id: CharacterId(1),
+            pos: PosAxial::new(3, 3),
+            health_current: 10,
+            ..Default::default()
+        },
+    ]
+}
+
+fn choice(choice: AbilityChoice) -> ScriptedInputEntry {
+    ScriptedInputEntry::Action(ActionInput::AbilityChoice(choice))
+}
+
+#[test]
+pub fn test_engine_ability_choice_choose_one() {
+    let board = Board::rectangle(0..5, 0..5);
+    let mut characters = characters();
+    let mut play_state = PlayState::default();
+    let mut log = EventLog::default();
+    run_script_with_observer(
+        &mut play_state,
+        &mut ScriptedInput::new([
+            ScriptedInputEntry::PlayCard(0),
+            choice(AbilityChoice::Choose(1)),
+        ]),
+        &board,
+        &mut characters,
+        &mut log,
+    )
+    .unwrap();
+
+    assert_eq!(characters[0].conditions.get(&Condition::Retaliate), 2);
+    assert_eq!(characters[0].conditions.get(&Condition::Fortified), 0);
+    assert!(
+        log.inputs()
+            .any(|input| *input == RecordedInput::AbilityChoice(AbilityChoice::Choose(1)))
+    );
+}
+
+#[test]
+pub fn test_engine_ability_choice_rejected() {
+    let board = Board::rectangle(0..5, 0..5);
+    let mut characters = characters();
+    let mut play_state = PlayState::default();
+    let mut log = EventLog::default();
+    run_script_with_observer(
+        &mut play_state,
+        &mut ScriptedInput::new([ScriptedInputEntry::PlayCard(0)]),
+        &board,
+        &mut characters,
+        &mut log,
+    )
+    .unwrap();
+    assert_eq!(play_state.acting(), 0);
+
+    // Guard can not be declined, and has no third option
+    for invalid in [AbilityChoice::Decline, AbilityChoice::Choose(2)] {
+        let result = run_script_with_observer(
+            &mut play_state,
+            &mut ScriptedInput::new([choice(invalid)]),
+            &board,
+            &mut characters,
+            &mut log,
+        );
+        assert_eq!(result, Err(StepError::InvalidAbilityChoice(invalid)));
+    }
+    assert!(
+        !log.events()
+            .iter()
+            .any(|event| matches!(event, GameEvent::Input(RecordedInput::AbilityChoice(_))))
+    );
+
+    // The choice is still pending
+    run_script_with_observer(
+        &mut play_state,
+        &mut ScriptedInput::new([choice(AbilityChoice::Choose(0))]),
+        &board,
+        &mut characters,
+        &mut log,
+    )
+    .unwrap();
+    assert_eq!(characters[0].conditions.get(&Condition::Fortified), 2);
+}
@@ -0,0 +1,148 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    movement::MovementError,
+    test::tools::{
+        TestSetupError, play_card_with_inputs_on_board, single_movement_input,
+        single_targeted_input,
+    },
+};
+
+#[test]
+pub fn test_engine_board_walls_and_bounds() {
+    let board = Board::rectangle(0..1, 0..3).with_terrain(PosAxial::new(0, 1), Terrain::Wall);
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+
+    let result = play_card_with_inputs_on_board(
+        Card::Step,
+        &board,
+        character,
+        &mut vec![],
+        single_movement_input(vec![PosAxial::new(0, 1)]).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::Impassable { .. }
+        ))
+    ));
+
+    let result = play_card_with_inputs_on_board(
+        Card::Step,
+        &board,
+        character,
+        &mut vec![],
+        single_movement_input(vec![PosAxial::new(-1, 0)]).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::Impassable { .. }
+        ))
+    ));
+
+    let result = play_card_with_inputs_on_board(
+        Card::SteadyShot,
+        &board,
+        character,
+        &mut vec![],
+        single_targeted_input(PosAxial::new(-1, 0)).iter(),
+    );
+    assert!(matches!(result, Err(TestSetupError::InvalidTarget(_))));
+}
+
+#[test]
+pub fn test_engine_board_difficult_terrain() {
+    let board = Board::default()
+        .with_terrain(PosAxial::new(0, 1), Terrain::DifficultTerrain)
+        .with_terrain(PosAxial::new(0, 2), Terrain::DifficultTerrain);
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+
+    // Step moves 2 spaces, which only pays for a single hex of difficult terrain
+    let result = play_card_with_inputs_on_board(
+        Card::Step,
+        &board,
+        character,
+        &mut vec![],
+        single_movement_input(vec![PosAxial::new(0, 1), PosAxial::new(0, 2)]).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::PathTooLong { spaces: 2, cost: 4 }
+        ))
+    ));
+
+    play_card_with_inputs_on_board(
+        Card::Step,
+        &board,
+        character,
+        &mut vec![],
+        single_movement_input(vec![PosAxial::new(0, 1)]).iter(),
+    )
+    .unwrap();
+    assert_eq!(character.pos, PosAxial::new(0, 1));
+}
+
+#[test]
+pub fn test_engine_board_hazard() {
+    let hazard = PosAxial::new(0, 1);
+    let board = Board::default().with_terrain(hazard.clone(), Terrain::Hazard(3));
+    let character = &mut Character {
+        health_current: 10,
+        health_max: 10,
+        stamina_current: 10,
+        ..Default::default()
+    };
+
+    play_card_with_inputs_on_board(
+        Card::Step,
+        &board,
+        character,
+        &mut vec![],
+        single_movement_input(vec![hazard.clone(), PosAxial::new(0, 2)]).iter(),
+    )
+    .unwrap();
+    assert_eq!(character.health_current, 7);
+}
+
+#[test]
+pub fn test_engine_board_push_into_wall() {
+    let board = Board::default()
+        .with_terrain(PosAxial::new(0, 3), Terrain::Wall)
+        .with_terrain(PosAxial::new(-1, 3), Terrain::Obstacle)
+        .with_terrain(PosAxial::new(1, 2), Terrain::Wall);
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        pos: PosAxial::new(0, 2),
+        ..Default::default()
+    }];
+
+    play_card_with_inputs_on_board(
+        Card::Shove,
+        &board,
+        character,
+        other_characters,
+        [
+            single_movement_input(vec![PosAxial::new(0, 1)]),
+            single_targeted_input(PosAxial::new(0, 2)),
+        ]
+        .concat()
+        .iter(),
+    )
+    .unwrap();
+
+    assert_eq!(other_characters[0].pos, PosAxial::new(0, 2));
+}
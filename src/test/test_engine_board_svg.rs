@@ -0,0 +1,70 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    board_svg::render_svg,
+    board_view::BoardView,
+    data_model::{
+        Action, ActionMovement, Character, CharacterId, CharacterTeam, Condition, Conditions,
+    },
+    hex_grid::PosAxial,
+    monsters::MonsterType,
+    test::tools::character,
+};
+
+#[test]
+pub fn test_engine_board_svg() {
+    let board = Board::rectangle(0..3, 0..4).with_terrain(PosAxial::new(0, 0), Terrain::Hazard(2));
+    let characters = [
+        Character {
+            health_current: 5,
+            conditions: Conditions::default().with_incremented(Condition::Poison, 2),
+            ..character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20)
+        },
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let svg = render_svg(&BoardView::new(&board, &characters));
+
+    assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+    assert!(svg.ends_with("</svg>\n"));
+    // One hex per board hex, and an outline per character
+    assert_eq!(svg.matches("<polygon").count(), 12 + 2);
+    assert!(svg.contains(">P0<"));
+    assert!(svg.contains(">M1 Skeleton<"));
+    assert!(svg.contains(">5/20<"));
+    assert!(svg.contains(">Poi2<"));
+    assert!(svg.contains(">Hazard 2<"));
+    // Odd columns are shifted down half a hex, like in render_hex_grid
+    assert!(svg.contains(">0 0<"));
+    assert!(svg.contains(r##"<text x="60.0" y="10.6" font-size="8" fill="#666666">0 1</text>"##));
+}
+
+#[test]
+pub fn test_engine_board_svg_highlights() {
+    let board = Board::rectangle(0..3, 0..4);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let movement = Action::Movement(ActionMovement {
+        spaces: 1,
+        jump: false,
+    });
+    let board_view = BoardView::new(&board, &characters).with_pending_action(&movement, 0);
+    let svg = render_svg(&board_view);
+
+    // The six neighbors, except the skeleton's hex
+    assert_eq!(svg.matches("fill-opacity=\"0.4\"").count(), 5);
+}
+
+#[cfg(feature = "png")]
+#[test]
+pub fn test_engine_board_png() {
+    let board = Board::rectangle(0..3, 0..4);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let png = crate::board_svg::render_png(&render_svg(&BoardView::new(&board, &characters)));
+
+    assert!(png.unwrap().starts_with(b"\x89PNG"));
+}
@@ -0,0 +1,146 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    board_view::{BoardView, Highlight},
+    data_model::{
+        Action, ActionMovement, ActionOnSelf, ActionTargeted, AreaEffect, Character, CharacterId,
+        CharacterTeam, Condition, Conditions, Reach,
+    },
+    hex_grid::{Area, DistanceRange, PosAxial},
+    monsters::MonsterType,
+    render_hex_grid::HexContent,
+    test::tools::character,
+};
+
+fn highlighted(board_view: &BoardView, board: &Board, highlight: Highlight) -> Vec<PosAxial> {
+    let mut highlighted: Vec<PosAxial> = board
+        .hexes()
+        .unwrap()
+        .filter(|pos| board_view.highlight(pos) == Some(highlight))
+        .cloned()
+        .collect();
+    highlighted.sort();
+    highlighted
+}
+
+#[test]
+pub fn test_engine_board_view_hex_content() {
+    let board = Board::rectangle(0..4, 0..4).with_terrain(PosAxial::new(1, 1), Terrain::Hazard(2));
+    let characters = [
+        Character {
+            health_current: 5,
+            conditions: Conditions::default()
+                .with_incremented(Condition::Weak, 1)
+                .with_incremented(Condition::Poison, 2),
+            ..character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20)
+        },
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let board_view = BoardView::new(&board, &characters);
+    let pos = PosAxial::new(1, 1).to_odd_q();
+
+    let content: Vec<String> = (-2..=2)
+        .rev()
+        .map(|row| (&board_view).hex_content(&pos, row))
+        .collect();
+    assert_eq!(
+        content,
+        vec!["1 1", "P0", "██░░░░░░ 5", "Poi2 Wea1", "Hazard 2"]
+    );
+    let monster_name = (&board_view).hex_content(&PosAxial::new(1, 2).to_odd_q(), 1);
+    assert_eq!(monster_name, "M1 Skeleton");
+}
+
+#[test]
+pub fn test_engine_board_view_pending_action() {
+    let board = Board::rectangle(0..4, 0..4).with_terrain(PosAxial::new(2, 1), Terrain::Wall);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+
+    let movement = Action::Movement(ActionMovement {
+        spaces: 1,
+        jump: false,
+    });
+    let board_view = BoardView::new(&board, &characters).with_pending_action(&movement, 0);
+    // Neither the wall nor the skeleton's hex can be moved to
+    assert_eq!(
+        highlighted(&board_view, &board, Highlight::Movement),
+        vec![
+            PosAxial::new(0, 1),
+            PosAxial::new(0, 2),
+            PosAxial::new(1, 0),
+            PosAxial::new(2, 0),
+        ]
+    );
+
+    let melee = Action::Targeted(ActionTargeted {
+        reach: Reach::Melee,
+        effects: Vec::new(),
+    });
+    let board_view = BoardView::new(&board, &characters).with_pending_action(&melee, 0);
+    // Melee needs no line of sight, so even the wall can be targeted
+    assert_eq!(
+        highlighted(&board_view, &board, Highlight::LegalTarget).len(),
+        6
+    );
+
+    let around_self = Action::OnSelf(ActionOnSelf {
+        effects: vec![AreaEffect {
+            area: Area::Disk(DistanceRange { from: 1, to: 2 }),
+            ..Default::default()
+        }],
+    });
+    let board_view = BoardView::new(&board, &characters).with_pending_action(&around_self, 0);
+    assert_eq!(highlighted(&board_view, &board, Highlight::Area).len(), 6);
+    assert_eq!(board_view.highlight(&PosAxial::new(1, 1)), None);
+}
+
+#[test]
+pub fn test_engine_board_view_colors() {
+    let board = Board::rectangle(0..4, 0..4);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let plain = BoardView::new(&board, &characters).render();
+    let colored = BoardView::new(&board, &characters)
+        .with_colors(true)
+        .render();
+
+    assert!(!plain.contains('\x1b'));
+    assert!(colored.contains('\x1b'));
+    let without_escapes: String = colored
+        .split('\x1b')
+        .enumerate()
+        .map(|(i, part)| {
+            if i == 0 {
+                part
+            } else {
+                &part[part.find('m').unwrap() + 1..]
+            }
+        })
+        .collect();
+    assert_eq!(without_escapes, plain);
+}
+
+#[test]
+pub fn test_engine_board_view_cursor_and_path() {
+    let board = Board::rectangle(0..4, 0..4);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(1, 1), 20),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 2), 0),
+    ];
+    let board_view = BoardView::new(&board, &characters)
+        .with_path(&[PosAxial::new(0, 1), PosAxial::new(0, 2)])
+        .with_cursor(PosAxial::new(0, 2));
+
+    assert_eq!(
+        highlighted(&board_view, &board, Highlight::Path),
+        vec![PosAxial::new(0, 1), PosAxial::new(0, 2)]
+    );
+    let coordinates = |pos: PosAxial| (&board_view).hex_content(&pos.to_odd_q(), 2);
+    assert_eq!(coordinates(PosAxial::new(0, 1)), "=0 1=");
+    assert_eq!(coordinates(PosAxial::new(0, 2)), "[1 2]");
+}
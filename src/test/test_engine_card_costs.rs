@@ -0,0 +1,60 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterTeam, Condition},
+    deck::Deck,
+    hex_grid::PosAxial,
+    play::{PlayCardError, can_afford},
+    play_state::{PlayState, StepError},
+    test::tools::{ScriptedInput, ScriptedInputEntry, registry, run_script},
+};
+
+#[test]
+pub fn test_engine_card_costs() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            stamina_current: 4,
+            deck: Deck::with_hand([Card::Adrenaline, Card::Adrenaline]),
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 3),
+            ..Default::default()
+        },
+    ];
+    let card_data = |card: Card| registry().card(&card).unwrap();
+    assert!(can_afford(
+        &characters[0],
+        card_data(Card::Adrenaline),
+        registry()
+    ));
+    assert!(!can_afford(
+        &characters[0],
+        card_data(Card::Fury),
+        registry()
+    ));
+
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::PlayCard(0),
+        ScriptedInputEntry::PlayCard(0),
+    ]);
+    let result = run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    );
+
+    assert_eq!(
+        result,
+        Err(StepError::CanNotPlayCard(PlayCardError::NotEnoughStamina {
+            cost: 3,
+            available: 1
+        }))
+    );
+    assert_eq!(characters[0].stamina_current, 1);
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 2);
+}
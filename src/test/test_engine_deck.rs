@@ -0,0 +1,97 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam},
+    deck::{Deck, DeckRules},
+    hex_grid::PosAxial,
+    play::PlayCardError,
+    play_state::{PlayState, StepError, start_encounter},
+    test::tools::{ScriptedInput, ScriptedInputEntry, run_script},
+};
+
+fn characters_with_decks() -> [Character; 2] {
+    let rules = DeckRules {
+        starting_hand_size: 3,
+        cards_drawn_per_turn: 1,
+        hand_limit: 4,
+    };
+    [
+        Character {
+            id: CharacterId(0),
+            team: CharacterTeam::Player,
+            stamina_current: 20,
+            deck: Deck::new([Card::Adrenaline; 6]).with_rules(rules.clone()),
+            ..Default::default()
+        },
+        Character {
+            id: CharacterId(1),
+            pos: PosAxial::new(0, 3),
+            stamina_current: 20,
+            deck: Deck::new([Card::Calm; 6]).with_rules(rules),
+            ..Default::default()
+        },
+    ]
+}
+
+#[test]
+pub fn test_engine_deck_play_and_draw() {
+    let mut characters = characters_with_decks();
+    let mut play_state = PlayState::default().with_seed(11);
+    start_encounter(&mut play_state, &mut characters).unwrap();
+    assert_eq!(characters[0].deck.hand.len(), 3);
+    assert_eq!(characters[1].deck.hand.len(), 3);
+
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::PlayCard(2),
+        ScriptedInputEntry::EndTurn,
+        ScriptedInputEntry::EndTurn,
+    ]);
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    // One card played and one drawn at the start of the player's second turn
+    assert_eq!(characters[0].deck.hand.len(), 3);
+    assert_eq!(characters[0].deck.discard_pile, vec![Card::Adrenaline]);
+    assert_eq!(characters[0].deck.draw_pile.len(), 2);
+    // The monster drew at the start of its turn
+    assert_eq!(characters[1].deck.hand.len(), 4);
+}
+
+#[test]
+pub fn test_engine_deck_card_not_in_hand() {
+    let mut characters = characters_with_decks();
+    let mut play_state = PlayState::default();
+    start_encounter(&mut play_state, &mut characters).unwrap();
+
+    let mut input = ScriptedInput::new([ScriptedInputEntry::PlayCard(3)]);
+    let result = run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    );
+    assert_eq!(
+        result,
+        Err(StepError::CanNotPlayCard(PlayCardError::CardNotInHand {
+            index: 3
+        }))
+    );
+    assert_eq!(characters[0].deck.hand.len(), 3);
+}
+
+#[test]
+pub fn test_engine_deck_seeded() {
+    let mut a = characters_with_decks();
+    let mut b = characters_with_decks();
+    a[0].deck = Deck::new([Card::Step, Card::Cut, Card::Strike, Card::Dash, Card::Fury]);
+    b[0].deck = a[0].deck.clone();
+    start_encounter(&mut PlayState::default().with_seed(5), &mut a).unwrap();
+    start_encounter(&mut PlayState::default().with_seed(5), &mut b).unwrap();
+    assert_eq!(a[0].deck, b[0].deck);
+}
@@ -0,0 +1,148 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition, Conditions, Death},
+    deck::Deck,
+    encounter::{EncounterOutcome, Objective, evaluate_encounter},
+    hex_grid::PosAxial,
+    play_state::PlayState,
+    test::tools::{
+        ScriptedInput, ScriptedInputEntry, character, run_script, single_targeted_input,
+    },
+};
+
+fn dead(character: Character) -> Character {
+    Character {
+        death: Some(Death {
+            killer: None,
+            overkill: 0,
+        }),
+        ..character
+    }
+}
+
+#[test]
+pub fn test_engine_encounter_outcome_players_won() {
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::Strike, Card::Adrenaline]),
+            ..character(0, CharacterTeam::Player, PosAxial::new(0, 0), 10)
+        },
+        character(1, CharacterTeam::Monster, PosAxial::new(0, 1), 5),
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new(
+        [ScriptedInputEntry::PlayCard(0)]
+            .into_iter()
+            .chain(
+                single_targeted_input(PosAxial::new(0, 1))
+                    .into_iter()
+                    .map(ScriptedInputEntry::Action),
+            )
+            .chain([ScriptedInputEntry::PlayCard(0)]),
+    );
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(*play_state.outcome(), EncounterOutcome::PlayersWon);
+    // Nothing is played once the encounter is over
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 0);
+}
+
+#[test]
+pub fn test_engine_encounter_outcome_monsters_won() {
+    let mut characters = [
+        Character {
+            health_current: 1,
+            // Conditions count down at the beginning of the turn, before the poison deals damage
+            conditions: Conditions::default().with_incremented(Condition::Poison, 2),
+            ..character(0, CharacterTeam::Player, PosAxial::new(0, 0), 10)
+        },
+        character(1, CharacterTeam::Monster, PosAxial::new(0, 3), 5),
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([ScriptedInputEntry::EndTurn]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(*play_state.outcome(), EncounterOutcome::MonstersWon);
+}
+
+#[test]
+pub fn test_engine_encounter_outcome_objectives() {
+    let boss = character(2, CharacterTeam::Monster, PosAxial::new(0, 3), 5);
+    let characters = [
+        character(0, CharacterTeam::Player, PosAxial::new(0, 0), 10),
+        character(1, CharacterTeam::Monster, PosAxial::new(0, 2), 5),
+        boss.clone(),
+    ];
+    let boss_dead = [
+        character(0, CharacterTeam::Player, PosAxial::new(0, 0), 10),
+        character(1, CharacterTeam::Monster, PosAxial::new(0, 2), 5),
+        dead(boss.clone()),
+    ];
+
+    assert_eq!(
+        evaluate_encounter(&[Objective::DefeatAllMonsters], &characters),
+        EncounterOutcome::InProgress
+    );
+    assert_eq!(
+        evaluate_encounter(&[Objective::DefeatCharacter(CharacterId(2))], &characters),
+        EncounterOutcome::InProgress
+    );
+    assert_eq!(
+        evaluate_encounter(&[Objective::DefeatCharacter(CharacterId(2))], &boss_dead),
+        EncounterOutcome::PlayersWon
+    );
+    assert_eq!(
+        evaluate_encounter(&[Objective::ReachHex(PosAxial::new(0, 0))], &characters),
+        EncounterOutcome::PlayersWon
+    );
+
+    let escaped = Objective::Custom {
+        description: "Survive until the boss is Stunned".into(),
+        check: |characters| {
+            characters
+                .iter()
+                .any(|character| character.conditions.has(&Condition::Stunned))
+                .then_some(EncounterOutcome::Custom("The boss was stunned".into()))
+        },
+    };
+    let mut stunned = characters.clone();
+    *stunned[2].conditions.get_mut(Condition::Stunned) = 1;
+    assert_eq!(
+        evaluate_encounter(std::slice::from_ref(&escaped), &characters),
+        EncounterOutcome::InProgress
+    );
+    assert_eq!(
+        evaluate_encounter(&[escaped], &stunned),
+        EncounterOutcome::Custom("The boss was stunned".into())
+    );
+
+    // Losing every player character ends the encounter regardless of the objectives
+    assert_eq!(
+        evaluate_encounter(
+            &[Objective::ReachHex(PosAxial::new(0, 0))],
+            &[dead(character(
+                0,
+                CharacterTeam::Player,
+                PosAxial::new(0, 0),
+                10
+            ))]
+        ),
+        EncounterOutcome::MonstersWon
+    );
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition, Trigger},
+    deck::Deck,
+    encounter::Encounter,
+    event_log::{EventLog, ReplayError, replay},
+    game_event::{GameEvent, RecordedInput},
+    hex_grid::PosAxial,
+    items::Item,
+    play_state::{PlayState, start_encounter},
+    resolve_action::ActionInputTargeted,
+    save::save_encounter,
+    test::tools::{
+        ActionInput, ScriptedInput, ScriptedInputEntry, registry, run_script_with_observer,
+    },
+};
+
+fn encounter() -> Encounter {
+    let mut encounter = Encounter {
+        board: Board::rectangle(0..5, 0..5),
+        characters: vec![
+            Character {
+                id: CharacterId(0),
+                team: CharacterTeam::Player,
+                pos: PosAxial::new(1, 1),
+                health_current: 20,
+                health_max: 20,
+                stamina_current: 10,
+                stamina_max: 10,
+                deck: Deck::new([Card::Strike; 6]),
+                ..Default::default()
+            },
+            Character {
+                id: CharacterId(1),
+                pos: PosAxial::new(1, 2),
+                health_current: 15,
+                health_max: 15,
+                equipped_items: vec![Item::ChestplateOfTheEnraged],
+                deck: Deck::new([Card::Cut; 6]),
+                ..Default::default()
+            },
+        ],
+        play_state: PlayState::default().with_seed(99),
+    };
+    start_encounter(&mut encounter.play_state, &mut encounter.characters).unwrap();
+    encounter
+}
+
+fn strike(target: PosAxial) -> [ScriptedInputEntry; 2] {
+    [
+        ScriptedInputEntry::PlayCard(0),
+        ScriptedInputEntry::Action(ActionInput::Targeted(ActionInputTargeted { target })),
+    ]
+}
+
+#[test]
+pub fn test_engine_event_log_replay() {
+    let initial = encounter();
+    let mut encounter = initial.clone();
+    let mut log = EventLog::default();
+    let mut input = ScriptedInput::new(
+        strike(PosAxial::new(1, 2))
+            .into_iter()
+            .chain([ScriptedInputEntry::EndTurn, ScriptedInputEntry::EndTurn]),
+    );
+    run_script_with_observer(
+        &mut encounter.play_state,
+        &mut input,
+        &encounter.board,
+        &mut encounter.characters,
+        &mut log,
+    )
+    .unwrap();
+
+    for event in [
+        GameEvent::CardPlayed {
+            character: CharacterId(0),
+            card: Card::Strike,
+        },
+        GameEvent::TriggerFired {
+            character: CharacterId(1),
+            trigger: Trigger::DamageTaken,
+        },
+        GameEvent::Input(RecordedInput::Confirm),
+        GameEvent::ConditionApplied {
+            target: CharacterId(1),
+            condition: Condition::Strong,
+            value: 1,
+        },
+        GameEvent::TurnEnded {
+            character: CharacterId(0),
+        },
+        GameEvent::TurnEnded {
+            character: CharacterId(1),
+        },
+    ] {
+        assert!(
+            log.events().contains(&event),
+            "{:?} not in {:?}",
+            event,
+            log
+        );
+    }
+    assert!(log.events().iter().any(|event| matches!(
+        event,
+        GameEvent::DamageDealt {
+            target: CharacterId(1),
+            source: Some(CharacterId(0)),
+            ..
+        }
+    )));
+
+    let log: EventLog = ron::from_str(&ron::to_string(&log).unwrap()).unwrap();
+    let mut replayed = initial.clone();
+    let replay_log = replay(&log, &mut replayed, registry()).unwrap();
+
+    assert_eq!(replay_log, log);
+    assert_eq!(
+        save_encounter(&replayed).unwrap(),
+        save_encounter(&encounter).unwrap()
+    );
+}
+
+#[test]
+pub fn test_engine_event_log_rejected_input() {
+    let mut encounter = encounter();
+    let mut log = EventLog::default();
+    let mut input = ScriptedInput::new(strike(PosAxial::new(3, 3)));
+    let result = run_script_with_observer(
+        &mut encounter.play_state,
+        &mut input,
+        &encounter.board,
+        &mut encounter.characters,
+        &mut log,
+    );
+
+    assert!(result.is_err());
+    assert_eq!(
+        log.inputs().collect::<Vec<_>>(),
+        vec![&RecordedInput::PlayCard(0)]
+    );
+}
+
+#[test]
+pub fn test_engine_event_log_replay_reordered() {
+    let initial = encounter();
+    let mut encounter = initial.clone();
+    let mut log = EventLog::default();
+    run_script_with_observer(
+        &mut encounter.play_state,
+        &mut ScriptedInput::new(strike(PosAxial::new(1, 2))),
+        &encounter.board,
+        &mut encounter.characters,
+        &mut log,
+    )
+    .unwrap();
+    let mut inputs: Vec<RecordedInput> = log.inputs().cloned().collect();
+    inputs.swap(0, 1);
+    let mut reordered = EventLog::default();
+    for input in inputs {
+        reordered.push(GameEvent::Input(input));
+    }
+
+    // The target is recorded where the card to play is asked for
+    assert_eq!(
+        replay(&reordered, &mut initial.clone(), registry()),
+        Err(ReplayError::Diverged { replayed: 0 })
+    );
+}
@@ -0,0 +1,137 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Ability, ActionMovement, ActionOnSelf, ActionTargeted, Character, CharacterTeam},
+    deck::Deck,
+    encounter::{Encounter, EncounterOutcome},
+    game_event::GameEvent,
+    game_loop::{FrontEnd, play, play_against},
+    hex_grid::PosAxial,
+    monster_ai::{MonsterAi, Strategy, View},
+    play_state::{AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn},
+    registry::Registry,
+    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+    test::tools::{character, registry},
+};
+
+// Never answers, and quits once the players have been asked often enough.
+#[derive(Default)]
+struct Silent {
+    polls: usize,
+    errors: Vec<String>,
+    outcome: Option<EncounterOutcome>,
+}
+
+impl Input for Silent {
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Pending
+    }
+    fn poll_action_input_targeted(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        PendingInput::Pending
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        PendingInput::Pending
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        _: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        PendingInput::Pending
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        PendingInput::Pending
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        PendingInput::Pending
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        self.polls += 1;
+        PendingInput::Pending
+    }
+    fn poll_ability_choice(&mut self, _: &Ability) -> PendingInput<AbilityChoice> {
+        PendingInput::Pending
+    }
+}
+
+impl FrontEnd for Silent {
+    fn quit(&self) -> bool {
+        self.polls >= 10
+    }
+    fn show(&mut self, _: &Encounter, _: &Registry, _: &[GameEvent]) {}
+    fn show_error(&mut self, message: &str) {
+        self.errors.push(message.into());
+    }
+    fn show_outcome(&mut self, outcome: &EncounterOutcome) {
+        self.outcome = Some(outcome.clone());
+    }
+}
+
+// Plays its first card, and targets a hex off the board.
+struct OffTarget;
+
+impl Strategy for OffTarget {
+    fn choose_card(&mut self, _: &View) -> Option<usize> {
+        Some(0)
+    }
+    fn choose_target(&mut self, _: &ActionTargeted, _: &View) -> Option<PosAxial> {
+        Some(PosAxial::new(9, 9))
+    }
+}
+
+#[test]
+pub fn test_engine_game_loop_empty_encounter() {
+    let mut front_end = Silent::default();
+    play(&mut Encounter::default(), registry(), &mut front_end);
+
+    assert_eq!(
+        front_end.errors,
+        vec!["There is no character to play, stopping".to_string()]
+    );
+    assert_eq!(front_end.outcome, None);
+}
+
+#[test]
+pub fn test_engine_game_loop_rejected_monster_input() {
+    let mut encounter = Encounter {
+        board: Board::rectangle(0..3, 0..3),
+        characters: vec![
+            Character {
+                deck: Deck::with_hand([Card::Cut]),
+                ..character(0, CharacterTeam::Monster, PosAxial::new(1, 0), 10)
+            },
+            character(1, CharacterTeam::Player, PosAxial::new(1, 1), 10),
+        ],
+        ..Default::default()
+    };
+    let mut front_end = Silent::default();
+    play_against(
+        &mut encounter,
+        registry(),
+        &mut front_end,
+        MonsterAi::new(OffTarget, registry()),
+    );
+
+    // Stopped at once, instead of asking the AI again or going on to the player
+    assert_eq!(front_end.polls, 0);
+    let error = front_end.errors.last().unwrap();
+    assert!(
+        error.starts_with("Monster input rejected, stopping: Invalid target"),
+        "{}",
+        error
+    );
+    assert_eq!(front_end.outcome, None);
+    assert_eq!(encounter.characters[1].health_current, 10);
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    data_model::{Character, CharacterId, CharacterTeam},
+    game_event::GameEvent,
+    hex_grid::PosAxial,
+    items::Item,
+    play_state::PlayState,
+    test::tools::{ScriptedInput, ScriptedInputEntry, run_script_with_observer},
+};
+
+#[test]
+pub fn test_engine_initiative_turn_order_and_round_hooks() {
+    let board = Board::rectangle(0..5, 0..5);
+    let character = |id: usize, team: CharacterTeam, initiative: usize| Character {
+        id: CharacterId(id),
+        team,
+        pos: PosAxial::new(id as isize, 0),
+        health_current: 10,
+        health_max: 10,
+        initiative,
+        ..Default::default()
+    };
+    let mut characters = [
+        Character {
+            health_current: 5,
+            equipped_items: vec![Item::HourglassAmulet],
+            ..character(0, CharacterTeam::Player, 50)
+        },
+        character(1, CharacterTeam::Monster, 10),
+        character(2, CharacterTeam::Player, 30),
+        // Ties keep the order of the characters
+        character(3, CharacterTeam::Monster, 10),
+    ];
+    let mut input = ScriptedInput::new((0..4).map(|_| ScriptedInputEntry::EndTurn));
+    let mut events = Vec::new();
+    run_script_with_observer(
+        &mut PlayState::default(),
+        &mut input,
+        &board,
+        &mut characters,
+        &mut |event: &GameEvent| {
+            if matches!(
+                event,
+                GameEvent::RoundBegan { .. }
+                    | GameEvent::RoundEnded { .. }
+                    | GameEvent::TurnBegan { .. }
+                    | GameEvent::HealthRestored { .. }
+            ) {
+                events.push(event.clone())
+            }
+        },
+    )
+    .unwrap();
+
+    let turn_order = vec![
+        CharacterId(1),
+        CharacterId(3),
+        CharacterId(2),
+        CharacterId(0),
+    ];
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::RoundBegan {
+                round: 0,
+                turn_order: turn_order.clone(),
+            },
+            GameEvent::HealthRestored {
+                target: CharacterId(0),
+                amount: 2,
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(1),
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(3),
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(2),
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(0),
+            },
+            GameEvent::RoundEnded { round: 0 },
+            GameEvent::RoundBegan {
+                round: 1,
+                turn_order,
+            },
+            GameEvent::HealthRestored {
+                target: CharacterId(0),
+                amount: 2,
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(1),
+            },
+        ]
+    );
+    assert_eq!(characters[0].health_current, 9);
+}
@@ -0,0 +1,171 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    data_model::{
+        Ability, AbilityKind, Action, ActionMovement, Character, CharacterId, CharacterTeam,
+        Condition, EffectOnCharacter,
+    },
+    event_log::EventLog,
+    game_event::GameEvent,
+    hex_grid::{PosAxial, distance},
+    monster_ai::{Aggressive, MonsterAi},
+    monsters::{MonsterAbilityCard, MonsterStats, MonsterType, monster_abilities},
+    play_state::{PlayState, TurnPhase, step_play_state},
+    test::tools::{character, registry},
+};
+
+// Steps the engine with the AI for every character until the given number of turns have ended and
+// the next turn has reached its main phase.
+fn run_turns(
+    play_state: &mut PlayState,
+    board: &Board,
+    characters: &mut [Character],
+    turns: usize,
+    log: &mut EventLog,
+) {
+    let mut ai = MonsterAi::new(Aggressive, registry());
+    for _ in 0..100 {
+        if play_state.turn() >= turns && play_state.phase() == TurnPhase::Main {
+            return;
+        }
+        ai.update(board, characters, play_state.acting());
+        step_play_state(play_state, &mut ai, board, characters, registry(), log).unwrap();
+    }
+    panic!("The turns did not end");
+}
+
+fn revealed_cards(log: &EventLog) -> Vec<(MonsterType, usize)> {
+    log.events()
+        .iter()
+        .filter_map(|event| match event {
+            GameEvent::MonsterCardRevealed { monster_type, card } => Some((*monster_type, *card)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_engine_monster_abilities_shared_card() {
+    let board = Board::rectangle(0..8, 0..8);
+    let mut characters = [
+        MonsterType::Skeleton.spawn(CharacterId(0), PosAxial::new(0, 1), 0),
+        MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(1, 0), 0),
+        Character {
+            initiative: 99, // After every monster card
+            ..character(2, CharacterTeam::Player, PosAxial::new(5, 5), 30)
+        },
+    ];
+    let distances_before: Vec<usize> = characters[..2]
+        .iter()
+        .map(|skeleton| distance(&skeleton.pos, &characters[2].pos))
+        .collect();
+    let mut play_state = PlayState::default().with_seed(5);
+    let mut log = EventLog::default();
+
+    run_turns(&mut play_state, &board, &mut characters, 2, &mut log);
+
+    let revealed = revealed_cards(&log);
+    assert_eq!(revealed.len(), 1);
+    let (monster_type, card) = revealed[0];
+    assert_eq!(monster_type, MonsterType::Skeleton);
+    let card_data = &MonsterType::Skeleton.data().ability_cards[card];
+    let moves = card_data
+        .abilities
+        .iter()
+        .flat_map(|ability| &ability.actions)
+        .any(|action| matches!(action, Action::Movement(_)));
+    for (skeleton, distance_before) in characters[..2].iter().zip(distances_before) {
+        if moves {
+            assert!(distance(&skeleton.pos, &characters[2].pos) < distance_before);
+        } else {
+            assert!(skeleton.conditions.has(&Condition::Fortified));
+        }
+    }
+
+    // The player's turn ends the round, and the next card is revealed for the next one
+    run_turns(&mut play_state, &board, &mut characters, 3, &mut log);
+    assert_eq!(revealed_cards(&log).len(), 2);
+    assert_eq!(play_state.round(), 1);
+}
+
+#[test]
+pub fn test_engine_monster_abilities_monster_level() {
+    let board = Board::rectangle(0..8, 0..8);
+    let level = 2;
+    let mut characters = [
+        MonsterType::Skeleton.spawn(CharacterId(0), PosAxial::new(2, 2), level),
+        Character {
+            initiative: 99, // After every monster card
+            ..character(1, CharacterTeam::Player, PosAxial::new(2, 3), 30)
+        },
+    ];
+    let mut play_state = PlayState::default().with_seed(11).with_monster_level(level);
+    let mut log = EventLog::default();
+
+    run_turns(&mut play_state, &board, &mut characters, 1, &mut log);
+
+    let card = play_state
+        .revealed_monster_card(MonsterType::Skeleton)
+        .unwrap();
+    let damage: usize = monster_abilities(
+        &MonsterType::Skeleton.data().ability_cards[card],
+        &MonsterType::Skeleton.stats(play_state.monster_level()),
+    )
+    .iter()
+    .flat_map(|ability| &ability.actions)
+    .filter_map(|action| match action {
+        Action::Targeted(action) => Some(&action.effects),
+        _ => None,
+    })
+    .flatten()
+    .flat_map(|area_effect| &area_effect.effects)
+    .filter_map(|effect| match effect {
+        EffectOnCharacter::Damage(damage) => Some(*damage),
+        _ => None,
+    })
+    .sum();
+    assert!(damage >= MonsterType::Skeleton.stats(level).damage);
+    assert_eq!(characters[0].health_max, 7);
+    assert_eq!(characters[1].health_current, 30 - damage);
+}
+
+#[test]
+pub fn test_engine_monster_abilities_keep_kind() {
+    let movement = |spaces| {
+        Action::Movement(ActionMovement {
+            spaces,
+            jump: false,
+        })
+    };
+    let card = MonsterAbilityCard {
+        name: "Hesitate".into(),
+        initiative: 50,
+        shuffle: false,
+        abilities: vec![
+            Ability {
+                precondition: None,
+                kind: AbilityKind::Optional,
+                actions: vec![movement(1)],
+            },
+            Ability {
+                precondition: None,
+                kind: AbilityKind::ChooseOne(vec![vec![movement(2)], vec![movement(3)]]),
+                actions: Vec::new(),
+            },
+        ],
+    };
+    let stats = MonsterStats {
+        health: 5,
+        movement: 1,
+        damage: 1,
+    };
+
+    let abilities = monster_abilities(&card, &stats);
+
+    assert_eq!(abilities[0].kind, AbilityKind::Optional);
+    assert_eq!(abilities[0].actions, vec![movement(2)]);
+    assert_eq!(
+        abilities[1].kind,
+        AbilityKind::ChooseOne(vec![vec![movement(3)], vec![movement(4)]])
+    );
+}
@@ -0,0 +1,199 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    cards::Card,
+    data_model::{Character, CharacterTeam, Condition, Conditions},
+    deck::Deck,
+    event_log::EventLog,
+    hex_grid::{PosAxial, PosOddQHex, distance},
+    monster_ai::{Aggressive, MonsterAi, Policy, Strategy, View},
+    play_state::{PlayState, step_play_state},
+    registry::Registry,
+    test::tools::{character, registry},
+};
+
+// Steps the engine with the AI until the monster, which has the first turn, ends it.
+fn run_monster_turn<S: Strategy>(
+    ai: &mut MonsterAi<S>,
+    board: &Board,
+    characters: &mut [Character],
+    registry: &Registry,
+) {
+    let play_state = &mut PlayState::default();
+    for _ in 0..100 {
+        if play_state.turn() > 0 {
+            return;
+        }
+        ai.update(board, characters, play_state.acting());
+        step_play_state(
+            play_state,
+            ai,
+            board,
+            characters,
+            registry,
+            &mut EventLog::default(),
+        )
+        .unwrap();
+    }
+    panic!("The monster did not end its turn");
+}
+
+#[test]
+pub fn test_engine_monster_ai_moves_and_attacks() {
+    // The wall is in the way of the direct path
+    let board = Board::rectangle(0..6, 0..6).with_terrain(PosAxial::new(1, 2), Terrain::Wall);
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::Dash, Card::Cut]),
+            ..character(0, CharacterTeam::Monster, PosAxial::new(1, 0), 10)
+        },
+        character(1, CharacterTeam::Player, PosAxial::new(1, 3), 10),
+    ];
+
+    run_monster_turn(
+        &mut MonsterAi::new(Aggressive, registry()),
+        &board,
+        &mut characters,
+        registry(),
+    );
+
+    assert_eq!(distance(&characters[0].pos, &characters[1].pos), 1);
+    assert_eq!(characters[1].health_current, 8);
+    assert!(characters[0].deck.hand.is_empty());
+}
+
+#[test]
+pub fn test_engine_monster_ai_targets_weakest() {
+    let board = Board::rectangle(0..6, 0..6);
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::Cut, Card::Step]),
+            ..character(0, CharacterTeam::Monster, PosAxial::new(2, 2), 10)
+        },
+        character(1, CharacterTeam::Player, PosAxial::new(2, 3), 10),
+        character(2, CharacterTeam::Player, PosAxial::new(3, 2), 4),
+    ];
+
+    run_monster_turn(
+        &mut MonsterAi::new(Aggressive, registry()),
+        &board,
+        &mut characters,
+        registry(),
+    );
+
+    assert_eq!(characters[1].health_current, 10);
+    assert_eq!(characters[2].health_current, 2);
+    // Already next to an enemy, so there is nowhere better to move
+    assert_eq!(characters[0].pos, PosAxial::new(2, 2));
+    assert_eq!(characters[0].deck.hand, vec![Card::Step]);
+}
+
+// Never plays a card
+struct Idle;
+
+impl Strategy for Idle {
+    fn choose_card(&mut self, _: &View) -> Option<usize> {
+        None
+    }
+}
+
+#[test]
+pub fn test_engine_monster_ai_custom_strategy() {
+    let board = Board::rectangle(0..6, 0..6);
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::Cut]),
+            ..character(0, CharacterTeam::Monster, PosAxial::new(2, 2), 10)
+        },
+        character(1, CharacterTeam::Player, PosAxial::new(2, 3), 10),
+    ];
+
+    run_monster_turn(
+        &mut MonsterAi::new(Idle, registry()),
+        &board,
+        &mut characters,
+        registry(),
+    );
+
+    assert_eq!(characters[1].health_current, 10);
+    assert_eq!(characters[0].stamina_current, 10);
+}
+
+#[test]
+pub fn test_engine_monster_ai_ignorant_policy() {
+    let board = Board::rectangle(0..6, 0..6);
+    let mut characters = [
+        Character {
+            stamina_current: 1,
+            deck: Deck::with_hand([Card::Step, Card::Cut]),
+            ..character(0, CharacterTeam::Monster, PosAxial::new(2, 2), 10)
+        },
+        character(1, CharacterTeam::Player, PosAxial::new(2, 3), 10),
+    ];
+
+    run_monster_turn(
+        &mut MonsterAi::new(Policy::Ignorant, registry()),
+        &board,
+        &mut characters,
+        registry(),
+    );
+
+    // Aggressive would have attacked, but the first card in hand was affordable too
+    assert_eq!(characters[1].health_current, 10);
+    assert_eq!(characters[0].deck.hand, vec![Card::Cut]);
+}
+
+#[test]
+pub fn test_engine_monster_ai_attack_without_target() {
+    let mut registry = registry().clone();
+    registry
+        .load_str(
+            "cards.ron",
+            r#"(
+    cards: {
+        "Lunge": (
+            description: "Move 1, then deal 2 damage",
+            stamina_cost: 1,
+            abilities: [(actions: [
+                Movement(spaces: 1, jump: false),
+                Targeted(reach: Melee, effects: [(effects: [Damage(2)])]),
+            ])],
+        ),
+    },
+)"#,
+        )
+        .unwrap();
+    // The only hex next to the monster holds an Invisible player, so nothing can be attacked
+    let board = Board::rectangle(0..1, 0..2);
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::new("Lunge")]),
+            ..character(
+                0,
+                CharacterTeam::Monster,
+                PosOddQHex::new(0, 0).to_axial(),
+                10,
+            )
+        },
+        Character {
+            conditions: Conditions::default().with_incremented(Condition::Invisible, 2),
+            ..character(
+                1,
+                CharacterTeam::Player,
+                PosOddQHex::new(0, 1).to_axial(),
+                10,
+            )
+        },
+    ];
+
+    // Once the monster has moved the attack can not be canceled, and is dropped
+    run_monster_turn(
+        &mut MonsterAi::new(Policy::Ignorant, &registry),
+        &board,
+        &mut characters,
+        &registry,
+    );
+
+    assert_eq!(characters[1].health_current, 10);
+    assert!(characters[0].deck.hand.is_empty());
+}
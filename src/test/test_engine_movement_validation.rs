@@ -0,0 +1,166 @@
+#![cfg(test)]
+use std::collections::HashSet;
+
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{ActionMovement, Character, Condition, Conditions},
+    hex_grid::PosAxial,
+    movement::{MovementError, check_movement},
+    test::tools::{TestSetupError, play_card_with_inputs, single_movement_input},
+};
+
+fn play_step(
+    card: Card,
+    character: &mut Character,
+    other_characters: &mut Vec<Character>,
+    path: Vec<PosAxial>,
+) -> Result<(), TestSetupError> {
+    play_card_with_inputs(
+        card,
+        character,
+        other_characters,
+        single_movement_input(path).iter(),
+    )
+}
+
+#[test]
+pub fn test_engine_movement_validation_path_too_long() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let result = play_step(
+        Card::Step,
+        character,
+        &mut vec![],
+        vec![
+            PosAxial::new(0, 1),
+            PosAxial::new(0, 2),
+            PosAxial::new(0, 3),
+        ],
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::PathTooLong { .. }
+        ))
+    ));
+    assert_eq!(character.pos, PosAxial::default());
+}
+
+#[test]
+pub fn test_engine_movement_validation_not_adjacent() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let result = play_step(
+        Card::Dash,
+        character,
+        &mut vec![],
+        vec![PosAxial::new(0, 2)],
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::StepNotAdjacent { .. }
+        ))
+    ));
+    assert_eq!(character.pos, PosAxial::default());
+}
+
+#[test]
+pub fn test_engine_movement_validation_occupied() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        pos: PosAxial::new(0, 1),
+        ..Default::default()
+    }];
+
+    let result = play_step(
+        Card::Dash,
+        character,
+        other_characters,
+        vec![PosAxial::new(0, 1), PosAxial::new(0, 2)],
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::PathBlocked { .. }
+        ))
+    ));
+
+    let result = play_step(Card::Step, character, other_characters, vec![]);
+    assert!(result.is_ok());
+
+    let result = play_step(
+        Card::Step,
+        character,
+        other_characters,
+        vec![PosAxial::new(0, 1)],
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::DestinationOccupied { .. }
+        ))
+    ));
+}
+
+#[test]
+pub fn test_engine_movement_validation_jump() {
+    let character = Character::default();
+    let occupied = HashSet::from([PosAxial::new(0, 1)]);
+    let path = [PosAxial::new(0, 1), PosAxial::new(0, 2)];
+    let walk = ActionMovement {
+        spaces: 2,
+        jump: false,
+    };
+    let jump = ActionMovement {
+        spaces: 2,
+        jump: true,
+    };
+
+    assert!(check_movement(&walk, &Board::default(), &character, &occupied, &path).is_err());
+    assert!(check_movement(&jump, &Board::default(), &character, &occupied, &path).is_ok());
+    assert!(check_movement(&jump, &Board::default(), &character, &occupied, &path[..1]).is_err());
+}
+
+#[test]
+pub fn test_engine_movement_validation_immobilized() {
+    let action = ActionMovement {
+        spaces: 2,
+        jump: false,
+    };
+    let path = [PosAxial::new(0, 1), PosAxial::new(0, 2)];
+    let immobilized = Character {
+        conditions: Conditions::default().with_incremented(Condition::Immobilized, 1),
+        ..Default::default()
+    };
+    let occupied = HashSet::new();
+
+    assert!(
+        check_movement(
+            &action,
+            &Board::default(),
+            &Character::default(),
+            &occupied,
+            &path
+        )
+        .is_ok()
+    );
+    assert_eq!(
+        check_movement(
+            &action,
+            &Board::default(),
+            &immobilized,
+            &occupied,
+            &path[..1]
+        ),
+        Err(MovementError::Immobilized)
+    );
+}
@@ -0,0 +1,98 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition, Conditions},
+    deck::Deck,
+    game_event::GameEvent,
+    hex_grid::PosAxial,
+    play_state::PlayState,
+    test::tools::{
+        ScriptedInput, ScriptedInputEntry, run_script_with_observer, single_movement_input,
+    },
+};
+
+#[test]
+pub fn test_engine_observer() {
+    let board = Board::rectangle(0..5, 0..5).with_terrain(PosAxial::new(2, 1), Terrain::Hazard(2));
+    let mut characters = [
+        Character {
+            id: CharacterId(0),
+            team: CharacterTeam::Player,
+            pos: PosAxial::new(1, 1),
+            health_current: 10,
+            health_max: 10,
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Step]),
+            ..Default::default()
+        },
+        Character {
+            id: CharacterId(1),
+            pos: PosAxial::new(0, 3),
+            health_current: 10,
+            conditions: Conditions::default().with_incremented(Condition::Weak, 1),
+            ..Default::default()
+        },
+    ];
+    let mut input = ScriptedInput::new(
+        [ScriptedInputEntry::PlayCard(0)]
+            .into_iter()
+            .chain(
+                single_movement_input(vec![PosAxial::new(2, 1), PosAxial::new(3, 1)])
+                    .into_iter()
+                    .map(ScriptedInputEntry::Action),
+            )
+            .chain([ScriptedInputEntry::EndTurn]),
+    );
+    let mut events = Vec::new();
+    run_script_with_observer(
+        &mut PlayState::default(),
+        &mut input,
+        &board,
+        &mut characters,
+        &mut |event: &GameEvent| {
+            if !matches!(event, GameEvent::Input(_) | GameEvent::CardPlayed { .. }) {
+                events.push(event.clone())
+            }
+        },
+    )
+    .unwrap();
+
+    assert_eq!(
+        events,
+        vec![
+            GameEvent::RoundBegan {
+                round: 0,
+                turn_order: vec![CharacterId(0), CharacterId(1)],
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(0),
+            },
+            GameEvent::Moved {
+                character: CharacterId(0),
+                from: PosAxial::new(1, 1),
+                to: PosAxial::new(2, 1),
+            },
+            GameEvent::DamageDealt {
+                target: CharacterId(0),
+                source: None,
+                amount: 2,
+            },
+            GameEvent::Moved {
+                character: CharacterId(0),
+                from: PosAxial::new(2, 1),
+                to: PosAxial::new(3, 1),
+            },
+            GameEvent::TurnEnded {
+                character: CharacterId(0),
+            },
+            GameEvent::TurnBegan {
+                character: CharacterId(1),
+            },
+            GameEvent::ConditionExpired {
+                target: CharacterId(1),
+                condition: Condition::Weak,
+            },
+        ]
+    );
+}
@@ -0,0 +1,179 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{
+        Action, ActionTargeted, AreaEffect, Character, CharacterTeam, EffectOnCharacter, Reach,
+    },
+    deck::Deck,
+    event_log::EventLog,
+    hex_grid::PosAxial,
+    items::Item,
+    play::PlayCardError,
+    play_state::{PlayState, StepError, step_play_state},
+    registry::{Registry, RegistryErrorKind},
+    test::tools::{ScriptedInput, ScriptedInputEntry},
+};
+
+#[test]
+pub fn test_engine_registry_builtin_file() {
+    let registry = Registry::builtin();
+    assert!(registry.card(&Card::Cut).is_some());
+    assert!(registry.item(&Item::VolatileCore).is_some());
+    assert_eq!(registry.card(&Card::new("Missing")), None);
+
+    // Writing the definitions out and loading them again changes nothing
+    let mut reloaded = Registry::default();
+    reloaded
+        .load_str("reloaded.ron", &registry.to_ron())
+        .unwrap();
+    assert_eq!(reloaded.card_names(), registry.card_names());
+    assert_eq!(reloaded.item_names(), registry.item_names());
+    for name in registry.card_names() {
+        let card = Card::new(name);
+        assert_eq!(reloaded.card(&card), registry.card(&card), "{}", card);
+    }
+    for name in registry.item_names() {
+        let item = Item::new(name);
+        assert_eq!(reloaded.item(&item), registry.item(&item), "{}", item);
+    }
+}
+
+#[test]
+pub fn test_engine_registry_override() {
+    let builtin = Registry::builtin();
+    let mut registry = Registry::builtin();
+    registry
+        .load_str(
+            "balance.ron",
+            r#"(
+    cards: {
+        "Cut": (
+            description: "Deal 3 damage",
+            stamina_cost: 1,
+            abilities: [(actions: [Targeted(reach: Melee, effects: [(effects: [Damage(3)])])])],
+        ),
+    },
+)"#,
+        )
+        .unwrap();
+
+    let cut = registry.card(&Card::Cut).unwrap();
+    assert_eq!(cut.stamina_cost, 1);
+    assert_eq!(
+        cut.abilities[0].actions,
+        vec![Action::Targeted(ActionTargeted {
+            reach: Reach::Melee,
+            effects: vec![AreaEffect {
+                effects: vec![EffectOnCharacter::Damage(3)],
+                ..Default::default()
+            }],
+        })]
+    );
+    assert_eq!(registry.card(&Card::Strike), builtin.card(&Card::Strike));
+}
+
+#[test]
+pub fn test_engine_registry_played_card() {
+    let mut registry = Registry::builtin();
+    registry
+        .load_str(
+            "cards.ron",
+            r#"(
+    cards: {
+        "Mend": (
+            description: "Restore 4 health",
+            stamina_cost: 2,
+            abilities: [(actions: [OnSelf(effects: [(effects: [Heal(4)])])])],
+        ),
+    },
+)"#,
+        )
+        .unwrap();
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            health_current: 5,
+            health_max: 10,
+            stamina_current: 3,
+            deck: Deck::with_hand([Card::new("Mend"), Card::new("Missing")]),
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 3),
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::PlayCard(0),
+        ScriptedInputEntry::PlayCard(0),
+    ]);
+    let mut step = || {
+        step_play_state(
+            &mut play_state,
+            &mut input,
+            &Board::default(),
+            &mut characters,
+            &registry,
+            &mut EventLog::default(),
+        )
+    };
+    let result = (0..10).map(|_| step()).find(Result::is_err);
+
+    // The card is resolved with the loaded definition, and a card without one can not be played
+    assert_eq!(
+        result,
+        Some(Err(StepError::CanNotPlayCard(PlayCardError::UnknownCard {
+            index: 0
+        })))
+    );
+    assert_eq!(characters[0].health_current, 9);
+    assert_eq!(characters[0].stamina_current, 1);
+}
+
+#[test]
+pub fn test_engine_registry_parse_error() {
+    let mut registry = Registry::default();
+    let err = registry
+        .load_str(
+            "broken.ron",
+            "(\n    cards: {\n        \"Cut\": (\n            stamina_cost: \"one\",\n",
+        )
+        .unwrap_err();
+    assert_eq!(err.file, "broken.ron");
+    assert_eq!(err.line, 4);
+    assert!(matches!(err.kind, RegistryErrorKind::Parse(_)));
+    assert!(err.to_string().starts_with("broken.ron:4:"));
+}
+
+#[test]
+pub fn test_engine_registry_validation_error() {
+    let mut registry = Registry::default();
+    let err = registry
+        .load_str(
+            "cards.ron",
+            r#"(
+    cards: {
+        "Cut": (
+            description: "Deal 2 damage",
+            stamina_cost: 1,
+            abilities: [(actions: [Targeted(reach: Melee, effects: [(effects: [Damage(2)])])])],
+        ),
+        "LongShot": (
+            description: "Deal 2 damage (Range 0)",
+            stamina_cost: 1,
+            abilities: [(actions: [Targeted(reach: Ranged(range: 0), effects: [(effects: [Damage(2)])])])],
+        ),
+    },
+)"#,
+        )
+        .unwrap_err();
+    assert_eq!((err.line, err.column), (8, 9));
+    assert!(matches!(
+        err.kind,
+        RegistryErrorKind::Invalid { ref name, .. } if name == "LongShot"
+    ));
+    // Nothing from an invalid file is added
+    assert_eq!(registry.card(&Card::Cut), None);
+}
@@ -0,0 +1,100 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{
+        Ability, AbilityKind, Action, ActionMovement, ActionTargeted, AreaEffect, Character,
+        CharacterTeam, EffectOnCharacter, Reach,
+    },
+    hex_grid::PosAxial,
+    play_state::PlayState,
+    resolve_action::{ActionInputMovement, ActionInputTargeted},
+    test::tools::{
+        ActionInput, ScriptedInput, ScriptedInputEntry, play_card_with_inputs, run_script,
+        single_movement_input, single_targeted_input,
+    },
+};
+
+// The abilities of a card resolve in the order they are written on it, i.e. Shove moves before it
+// pushes.
+#[test]
+pub fn test_engine_resolution_order_of_card_abilities() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut [Character {
+        pos: PosAxial::new(0, 2),
+        ..Default::default()
+    }];
+
+    play_card_with_inputs(
+        Card::Shove,
+        character,
+        other_characters,
+        [
+            single_movement_input(vec![PosAxial::new(0, 1)]),
+            single_targeted_input(PosAxial::new(0, 2)),
+        ]
+        .concat()
+        .iter(),
+    )
+    .unwrap();
+
+    assert_eq!(character.pos, PosAxial::new(0, 1));
+    assert_eq!(other_characters[0].pos, PosAxial::new(0, 4));
+}
+
+// The actions of an ability resolve in the order they are written, i.e. the move comes before the
+// attack that needs it.
+#[test]
+pub fn test_engine_resolution_order_of_ability_actions() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            health_current: 10,
+            remaining_abilities: vec![Ability {
+                precondition: None,
+                kind: AbilityKind::Mandatory,
+                actions: vec![
+                    Action::Movement(ActionMovement {
+                        spaces: 1,
+                        jump: false,
+                    }),
+                    Action::Targeted(ActionTargeted {
+                        reach: Reach::Melee,
+                        effects: vec![AreaEffect {
+                            effects: vec![EffectOnCharacter::Damage(2)],
+                            ..Default::default()
+                        }],
+                    }),
+                ],
+            }],
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 2),
+            health_current: 10,
+            ..Default::default()
+        },
+    ];
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::Action(ActionInput::Movement(ActionInputMovement {
+            path: vec![PosAxial::new(0, 1)],
+        })),
+        ScriptedInputEntry::Action(ActionInput::Targeted(ActionInputTargeted {
+            target: PosAxial::new(0, 2),
+        })),
+    ]);
+
+    run_script(
+        &mut PlayState::default(),
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(characters[0].pos, PosAxial::new(0, 1));
+    assert_eq!(characters[1].health_current, 8);
+}
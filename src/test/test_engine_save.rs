@@ -0,0 +1,238 @@
+#![cfg(test)]
+use crate::{
+    board::{Board, Terrain},
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition, Conditions},
+    deck::Deck,
+    encounter::{Encounter, EncounterOutcome, Objective},
+    hex_grid::PosAxial,
+    items::Item,
+    play_state::{PlayState, start_encounter},
+    save::{SAVE_VERSION, SaveError, load_encounter, save_encounter},
+    test::tools::{ScriptedInput, ScriptedInputEntry, run_script, single_targeted_input},
+};
+
+fn encounter_in_progress() -> Encounter {
+    let mut encounter = Encounter {
+        board: Board::rectangle(0..5, 0..5)
+            .with_terrain(PosAxial::new(2, 2), Terrain::Wall)
+            .with_terrain(PosAxial::new(3, 1), Terrain::Hazard(2)),
+        characters: vec![
+            Character {
+                id: CharacterId(0),
+                team: CharacterTeam::Player,
+                pos: PosAxial::new(1, 1),
+                health_current: 20,
+                health_max: 20,
+                stamina_current: 10,
+                stamina_max: 10,
+                equipped_items: vec![Item::ChestplateOfTheEnraged, Item::StillrootLegs],
+                conditions: Conditions::default().with_incremented(Condition::Strong, 2),
+                deck: Deck::new([Card::Strike, Card::Step, Card::Cut, Card::Adrenaline]),
+                ..Default::default()
+            },
+            Character {
+                id: CharacterId(1),
+                pos: PosAxial::new(1, 2),
+                health_current: 15,
+                health_max: 15,
+                stamina_current: 10,
+                conditions: Conditions::default().with_incremented(Condition::Poison, 1),
+                deck: Deck::new([Card::Cut; 4]),
+                ..Default::default()
+            },
+        ],
+        play_state: PlayState::default().with_seed(1234),
+    };
+    start_encounter(&mut encounter.play_state, &mut encounter.characters).unwrap();
+    let strike = encounter.characters[0]
+        .deck
+        .hand
+        .iter()
+        .position(|card| *card == Card::Strike)
+        .unwrap();
+    // The Strike is left waiting for a target
+    let mut input = ScriptedInput::new([ScriptedInputEntry::PlayCard(strike)]);
+    run_script(
+        &mut encounter.play_state,
+        &mut input,
+        &encounter.board,
+        &mut encounter.characters,
+    )
+    .unwrap();
+    encounter
+}
+
+fn finish_strike_and_end_turns(encounter: &mut Encounter) {
+    let mut input = ScriptedInput::new(
+        single_targeted_input(PosAxial::new(1, 2))
+            .into_iter()
+            .map(ScriptedInputEntry::Action)
+            .chain([ScriptedInputEntry::EndTurn, ScriptedInputEntry::EndTurn]),
+    );
+    run_script(
+        &mut encounter.play_state,
+        &mut input,
+        &encounter.board,
+        &mut encounter.characters,
+    )
+    .unwrap();
+}
+
+#[test]
+pub fn test_engine_save_round_trip() {
+    let encounter = encounter_in_progress();
+    let saved = save_encounter(&encounter).unwrap();
+    let loaded = load_encounter(&saved).unwrap();
+
+    assert_eq!(loaded.board, encounter.board);
+    assert_eq!(loaded.characters, encounter.characters);
+    assert_eq!(save_encounter(&loaded).unwrap(), saved);
+}
+
+#[test]
+pub fn test_engine_save_continue_after_load() {
+    let mut encounter = encounter_in_progress();
+    let mut loaded = load_encounter(&save_encounter(&encounter).unwrap()).unwrap();
+
+    finish_strike_and_end_turns(&mut encounter);
+    finish_strike_and_end_turns(&mut loaded);
+
+    assert_eq!(encounter.characters[1].health_current, 9);
+    assert_eq!(encounter.play_state.turn(), 2);
+    // Including the cards drawn from the seeded decks
+    assert_eq!(
+        save_encounter(&loaded).unwrap(),
+        save_encounter(&encounter).unwrap()
+    );
+}
+
+#[test]
+pub fn test_engine_save_unsupported_version() {
+    let saved = save_encounter(&encounter_in_progress()).unwrap();
+    let from_the_future = saved.replacen(
+        &format!("version: {}", SAVE_VERSION),
+        &format!("version: {}", SAVE_VERSION + 1),
+        1,
+    );
+    assert!(matches!(
+        load_encounter(&from_the_future),
+        Err(SaveError::UnsupportedVersion { .. })
+    ));
+    assert!(matches!(
+        load_encounter(&format!(
+            "(version: {}, encounter: (board: ()))",
+            SAVE_VERSION
+        )),
+        Err(SaveError::Parse { .. })
+    ));
+}
+
+#[test]
+pub fn test_engine_save_custom_objective() {
+    let encounter = Encounter {
+        play_state: PlayState::with_objectives(vec![Objective::Custom {
+            description: "Never ends".into(),
+            check: |_| None::<EncounterOutcome>,
+        }]),
+        ..Default::default()
+    };
+    assert!(matches!(
+        save_encounter(&encounter),
+        Err(SaveError::Serialize(_))
+    ));
+}
+
+// Written by the first version of the format, later versions must keep loading it
+const VERSION_1_SAVE: &str = r#"(
+    version: 1,
+    encounter: (
+        board: (
+            hexes: Some([(r: 0, q: 0), (r: 0, q: 1), (r: 1, q: 0), (r: 1, q: 1)]),
+            terrain: [((r: 1, q: 1), Wall)],
+            characters_block_line_of_sight: false,
+        ),
+        characters: [
+            (
+                id: (0),
+                pos: (r: 0, q: 0),
+                team: Player,
+                health_current: 10,
+                health_max: 10,
+                stamina_current: 5,
+                stamina_max: 5,
+                equipped_items: ["StillrootLegs"],
+                conditions: {},
+                turn_stats: ([]),
+                remaining_abilities: [],
+                death: None,
+                deck: (
+                    rules: (starting_hand_size: 5, cards_drawn_per_turn: 2, hand_limit: 8),
+                    draw_pile: [],
+                    hand: ["Strike"],
+                    discard_pile: [],
+                    exhausted: [],
+                ),
+            ),
+            (
+                id: (1),
+                pos: (r: 0, q: 1),
+                team: Monster,
+                health_current: 6,
+                health_max: 6,
+                stamina_current: 0,
+                stamina_max: 0,
+                equipped_items: [],
+                conditions: {Poison: 1},
+                turn_stats: ([]),
+                remaining_abilities: [],
+                death: None,
+                deck: (
+                    rules: (starting_hand_size: 5, cards_drawn_per_turn: 2, hand_limit: 8),
+                    draw_pile: [],
+                    hand: [],
+                    discard_pile: [],
+                    exhausted: [],
+                ),
+            ),
+        ],
+        play_state: (
+            active: 0,
+            has_turn: 0,
+            remaining_actions: [],
+            cancelable: false,
+            turn_lost: false,
+            objectives: [DefeatAllMonsters],
+            outcome: InProgress,
+            rng: (state: 7),
+            turn: 0,
+        ),
+    ),
+)"#;
+
+#[test]
+pub fn test_engine_save_load_version_1() {
+    let mut encounter = load_encounter(VERSION_1_SAVE).unwrap();
+    assert_eq!(encounter.characters.len(), 2);
+    assert_eq!(encounter.characters[0].deck.hand, vec![Card::Strike]);
+    assert_eq!(
+        encounter.characters[1].conditions.get(&Condition::Poison),
+        1
+    );
+
+    let mut input = ScriptedInput::new(
+        [ScriptedInputEntry::PlayCard(0)].into_iter().chain(
+            single_targeted_input(PosAxial::new(0, 1))
+                .into_iter()
+                .map(ScriptedInputEntry::Action),
+        ),
+    );
+    run_script(
+        &mut encounter.play_state,
+        &mut input,
+        &encounter.board,
+        &mut encounter.characters,
+    )
+    .unwrap();
+    assert!(encounter.characters[1].health_current < 6);
+}
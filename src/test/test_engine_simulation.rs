@@ -0,0 +1,99 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam},
+    deck::Deck,
+    encounter::Encounter,
+    hex_grid::PosAxial,
+    items::Item,
+    monster_ai::Policy,
+    monsters::MonsterType,
+    simulate::{SimulationConfig, simulate},
+    test::tools::registry,
+};
+
+fn encounter(player_health: usize, cards: Vec<Card>) -> Encounter {
+    Encounter {
+        board: Board::rectangle(0..5, 0..5),
+        characters: vec![
+            Character {
+                id: CharacterId(0),
+                team: CharacterTeam::Player,
+                pos: PosAxial::new(1, 1),
+                health_current: player_health,
+                health_max: player_health,
+                stamina_current: 10,
+                stamina_max: 10,
+                deck: Deck::new(cards),
+                ..Default::default()
+            },
+            MonsterType::Skeleton.spawn(CharacterId(1), PosAxial::new(2, 2), 0),
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+pub fn test_engine_simulation() {
+    let template = encounter(60, vec![Card::Cut; 8]);
+    let config = SimulationConfig {
+        encounters: 10,
+        seed: 3,
+        ..Default::default()
+    };
+
+    let report = simulate(&template, &config, registry()).unwrap();
+
+    assert_eq!(report.encounters, 10);
+    assert_eq!(
+        report.players_won + report.monsters_won + report.unfinished,
+        10
+    );
+    assert!(report.players_won > 0);
+    assert!(report.rounds >= 10);
+    assert!(report.damage_to_monsters > 0);
+    assert!(report.stamina_spent > 0);
+    // The same seeds give the same encounters
+    assert_eq!(simulate(&template, &config, registry()), Ok(report));
+    // The template is left as it was
+    assert!(template.characters[0].deck.hand.is_empty());
+}
+
+#[test]
+pub fn test_engine_simulation_max_rounds() {
+    // Without cards the player can not hurt the skeleton, and does not die within the rounds
+    let template = encounter(1000, Vec::new());
+    let config = SimulationConfig {
+        encounters: 4,
+        player_policy: Policy::Ignorant,
+        max_rounds: 3,
+        ..Default::default()
+    };
+
+    let report = simulate(&template, &config, registry()).unwrap();
+
+    assert_eq!(report.unfinished, 4);
+    assert_eq!(report.win_rate(), 0.0);
+    assert_eq!(report.average_rounds(), 3.0);
+    assert_eq!(report.damage_to_monsters, 0);
+    assert!(report.damage_to_players > 0);
+    assert_eq!(report.stamina_spent, 0);
+    assert_eq!(report.player_turns, 4 * 3);
+}
+
+#[test]
+pub fn test_engine_simulation_health_paid_cards() {
+    let mut template = encounter(60, vec![Card::Cut; 8]);
+    template.characters[0].equipped_items = vec![Item::BoodboundHarness];
+    let config = SimulationConfig {
+        encounters: 4,
+        ..Default::default()
+    };
+
+    let report = simulate(&template, &config, registry()).unwrap();
+
+    // The cards were played, but paid with health
+    assert!(report.damage_to_monsters > 0);
+    assert_eq!(report.stamina_spent, 0);
+}
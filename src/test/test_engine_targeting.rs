@@ -0,0 +1,107 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    character_filter::CharacterFilter,
+    data_model::{
+        ActionTargeted, AreaEffect, Character, CharacterTeam, Condition, Conditions,
+        EffectOnCharacter, Reach,
+    },
+    hex_grid::PosAxial,
+    targeting::{TargetError, legal_targets},
+    test::tools::{TestSetupError, play_card_with_inputs, single_targeted_input},
+};
+
+#[test]
+pub fn test_engine_targeting_melee_adjacency() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        pos: PosAxial::new(0, 2),
+        health_current: 10,
+        ..Default::default()
+    }];
+
+    let result = play_card_with_inputs(
+        Card::Cut,
+        character,
+        other_characters,
+        single_targeted_input(PosAxial::new(0, 2)).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidTarget(
+            TargetError::NotAdjacent { .. }
+        ))
+    ));
+    assert_eq!(other_characters[0].health_current, 10);
+}
+
+#[test]
+pub fn test_engine_targeting_invisible() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        pos: PosAxial::new(0, 2),
+        health_current: 10,
+        conditions: Conditions::default().with_incremented(Condition::Invisible, 1),
+        ..Default::default()
+    }];
+
+    let result = play_card_with_inputs(
+        Card::SteadyShot,
+        character,
+        other_characters,
+        single_targeted_input(PosAxial::new(0, 2)).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidTarget(TargetError::Invisible { .. }))
+    ));
+    assert_eq!(other_characters[0].health_current, 10);
+}
+
+#[test]
+pub fn test_engine_targeting_legal_targets() {
+    let character = Character {
+        team: CharacterTeam::Player,
+        ..Default::default()
+    };
+    let enemy = Character {
+        team: CharacterTeam::Monster,
+        pos: PosAxial::new(0, 1),
+        ..Default::default()
+    };
+    let invisible_enemy = Character {
+        team: CharacterTeam::Monster,
+        pos: PosAxial::new(1, 0),
+        conditions: Conditions::default().with_incremented(Condition::Invisible, 1),
+        ..Default::default()
+    };
+    let ally = Character {
+        team: CharacterTeam::Player,
+        pos: PosAxial::new(-1, 0),
+        ..Default::default()
+    };
+    let characters = [&enemy, &invisible_enemy, &ally];
+    let action = ActionTargeted {
+        reach: Reach::Melee,
+        effects: vec![AreaEffect {
+            filter: Some(CharacterFilter::IsEnemy),
+            effects: vec![EffectOnCharacter::Damage(1)],
+            ..Default::default()
+        }],
+    };
+
+    let targets = legal_targets(&action, &Board::default(), &character, &characters);
+
+    assert_eq!(targets.len(), 4);
+    assert!(targets.contains(&enemy.pos));
+    assert!(!targets.contains(&invisible_enemy.pos));
+    assert!(!targets.contains(&ally.pos));
+    assert!(!targets.contains(&character.pos));
+}
@@ -0,0 +1,105 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition},
+    deck::Deck,
+    game_event::GameEvent,
+    hex_grid::PosAxial,
+    items::Item,
+    play_state::PlayState,
+    test::tools::{ScriptedInput, ScriptedInputEntry, character, run_script_with_observer},
+};
+
+// Returns the targets of the applied conditions, in order.
+fn run_whirlwind(characters: &mut [Character]) -> Vec<CharacterId> {
+    let mut play_state = PlayState::default();
+    let mut targets = Vec::new();
+    run_script_with_observer(
+        &mut play_state,
+        &mut ScriptedInput::new([ScriptedInputEntry::PlayCard(0)]),
+        &Board::rectangle(0..6, 0..6),
+        characters,
+        &mut |event: &GameEvent| {
+            if let GameEvent::ConditionApplied { target, .. } = event {
+                targets.push(*target);
+            }
+        },
+    )
+    .unwrap();
+    assert!(play_state.trigger_stack().is_empty());
+    targets
+}
+
+#[test]
+pub fn test_engine_trigger_stack_simultaneous_triggers() {
+    let mut characters = [
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            initiative: 10,
+            ..character(0, CharacterTeam::Monster, PosAxial::new(0, 0), 10)
+        },
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            initiative: 10,
+            ..character(1, CharacterTeam::Monster, PosAxial::new(1, 0), 10)
+        },
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            deck: Deck::with_hand([Card::Whirlwind]),
+            ..character(2, CharacterTeam::Player, PosAxial::new(2, 2), 10)
+        },
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            initiative: 10,
+            ..character(3, CharacterTeam::Monster, PosAxial::new(4, 4), 10)
+        },
+    ];
+
+    let targets = run_whirlwind(&mut characters);
+
+    // Everyone after the character that has the turn, in order, and then the character itself
+    assert_eq!(
+        targets,
+        vec![
+            CharacterId(3),
+            CharacterId(0),
+            CharacterId(1),
+            CharacterId(2)
+        ]
+    );
+}
+
+#[test]
+pub fn test_engine_trigger_stack_nested_triggers() {
+    let mut characters = [
+        Character {
+            deck: Deck::with_hand([Card::Whirlwind]),
+            ..character(0, CharacterTeam::Player, PosAxial::new(0, 0), 10)
+        },
+        Character {
+            equipped_items: vec![Item::VolatileCore],
+            initiative: 10,
+            ..character(1, CharacterTeam::Monster, PosAxial::new(2, 2), 1)
+        },
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            initiative: 10,
+            ..character(2, CharacterTeam::Monster, PosAxial::new(3, 2), 2)
+        },
+        Character {
+            equipped_items: vec![Item::ChestplateOfTheEnraged],
+            initiative: 10,
+            ..character(3, CharacterTeam::Monster, PosAxial::new(2, 3), 10)
+        },
+    ];
+
+    let targets = run_whirlwind(&mut characters);
+
+    // The explosion of the dying monster resolves first. It kills the second monster before its
+    // trigger resolves, and triggers the third monster again on top of its pending trigger.
+    assert!(!characters[2].is_alive());
+    assert_eq!(targets, vec![CharacterId(3), CharacterId(3)]);
+    assert_eq!(characters[3].conditions.get(&Condition::Strong), 2);
+    assert_eq!(characters[3].health_current, 6);
+}
@@ -0,0 +1,95 @@
+#![cfg(test)]
+use crate::{
+    board::Board,
+    data_model::{Character, CharacterId, CharacterTeam, Condition},
+    event_log::EventLog,
+    game_event::GameEvent,
+    hex_grid::PosAxial,
+    items::Item,
+    play_state::{PlayState, TurnPhase, step_play_state},
+    test::tools::{ScriptedInput, ScriptedInputEntry, registry},
+};
+
+#[test]
+pub fn test_engine_turn_phases_end_of_turn_triggers_resolve_first() {
+    let board = Board::rectangle(0..5, 0..5);
+    let mut characters = [
+        Character {
+            id: CharacterId(0),
+            team: CharacterTeam::Player,
+            pos: PosAxial::new(1, 1),
+            health_current: 10,
+            equipped_items: vec![Item::ThorngrownVest],
+            ..Default::default()
+        },
+        Character {
+            id: CharacterId(1),
+            pos: PosAxial::new(3, 3),
+            health_current: 10,
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([ScriptedInputEntry::EndTurn]);
+    let mut log = EventLog::default();
+    let mut phases = vec![play_state.phase()];
+    for _ in 0..12 {
+        step_play_state(
+            &mut play_state,
+            &mut input,
+            &board,
+            &mut characters,
+            registry(),
+            &mut log,
+        )
+        .unwrap();
+        if phases.last() != Some(&play_state.phase()) {
+            phases.push(play_state.phase());
+        }
+    }
+
+    assert_eq!(
+        phases,
+        vec![
+            TurnPhase::Cleanup,
+            TurnPhase::BeginTurn,
+            TurnPhase::Main,
+            TurnPhase::EndTurn,
+            TurnPhase::Cleanup,
+            TurnPhase::BeginTurn,
+            TurnPhase::Main,
+        ]
+    );
+    assert_eq!(play_state.has_turn(), 1);
+    let events: Vec<&GameEvent> = log
+        .events()
+        .iter()
+        .filter(|event| {
+            matches!(
+                event,
+                GameEvent::ConditionApplied { .. }
+                    | GameEvent::TurnEnded { .. }
+                    | GameEvent::TurnBegan { .. }
+            )
+        })
+        .collect();
+    assert_eq!(
+        events,
+        vec![
+            &GameEvent::TurnBegan {
+                character: CharacterId(0),
+            },
+            &GameEvent::TurnEnded {
+                character: CharacterId(0),
+            },
+            &GameEvent::ConditionApplied {
+                target: CharacterId(0),
+                condition: Condition::Retaliate,
+                value: 2,
+            },
+            &GameEvent::TurnBegan {
+                character: CharacterId(1),
+            },
+        ]
+    );
+}
@@ -2,9 +2,10 @@
 use crate::{
     cards::Card,
     data_model::Character,
+    event_log::EventLog,
     hex_grid::PosAxial,
     play::end_turn,
-    test::tools::{play_card_with_inputs, single_targeted_input},
+    test::tools::{play_card_with_inputs, registry, single_targeted_input},
     turn_stats::TurnStat,
 };
 
@@ -42,7 +43,7 @@ pub fn test_engine_turn_stat_attack_actions() {
 
     assert_eq!(character.turn_stats.get(0, &TurnStat::AttackActions), 2);
 
-    end_turn(character);
+    end_turn(character, registry(), &mut EventLog::default());
 
     assert_eq!(character.turn_stats.get(0, &TurnStat::AttackActions), 0);
     assert_eq!(character.turn_stats.get(1, &TurnStat::AttackActions), 2);
@@ -0,0 +1,56 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    items::Item,
+    test::tools::{play_card_with_inputs, resolve_remaining_abilities, single_targeted_input},
+};
+
+#[test]
+pub fn test_item_bloodletters_ring() {
+    let character = &mut Character {
+        health_current: 5,
+        health_max: 10,
+        stamina_current: 10,
+        equipped_items: vec![Item::BloodlettersRing],
+        ..Default::default()
+    };
+    let other_characters = &mut vec![
+        Character {
+            pos: PosAxial::new(0, 1),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(1, 0),
+            health_current: 2,
+            health_max: 10,
+            ..Default::default()
+        },
+    ];
+
+    // Damage without a kill does not trigger the ring
+    play_card_with_inputs(
+        Card::Cut,
+        character,
+        other_characters,
+        single_targeted_input(PosAxial::new(0, 1)).iter(),
+    )
+    .unwrap();
+    assert_eq!(character.health_current, 5);
+
+    play_card_with_inputs(
+        Card::Cut,
+        character,
+        other_characters,
+        single_targeted_input(PosAxial::new(1, 0)).iter(),
+    )
+    .unwrap();
+    resolve_remaining_abilities(character, other_characters, iter::empty()).unwrap();
+    assert!(!other_characters[1].is_alive());
+    assert_eq!(character.health_current, 8);
+}
@@ -0,0 +1,47 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    cards::Card,
+    data_model::Character,
+    items::Item,
+    play::{CardCost, PlayCardError, can_afford, card_cost},
+    test::tools::{TestSetupError, play_card_with_inputs, registry},
+};
+
+#[test]
+pub fn test_item_boodbound_harness() {
+    let character = &mut Character {
+        health_current: 6,
+        health_max: 10,
+        stamina_current: 0,
+        equipped_items: vec![Item::BoodboundHarness],
+        ..Default::default()
+    };
+    let card_data = registry().card(&Card::Adrenaline).unwrap();
+    assert_eq!(
+        card_cost(character, card_data, registry()),
+        CardCost::Health(3)
+    );
+    assert!(can_afford(character, card_data, registry()));
+
+    // Adrenaline costs 3 health and restores 3 health
+    play_card_with_inputs(Card::Adrenaline, character, &mut vec![], iter::empty()).unwrap();
+    assert_eq!(character.health_current, 6);
+    assert_eq!(character.stamina_current, 0);
+
+    // The last health point can not be spent
+    character.health_current = 3;
+    assert!(!can_afford(character, card_data, registry()));
+    let result = play_card_with_inputs(Card::Adrenaline, character, &mut vec![], iter::empty());
+    assert!(matches!(
+        result,
+        Err(TestSetupError::CanNotPlayCard(
+            PlayCardError::NotEnoughHealth {
+                cost: 3,
+                available: 3
+            }
+        ))
+    ));
+    assert_eq!(character.health_current, 3);
+}
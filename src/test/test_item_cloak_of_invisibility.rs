@@ -23,7 +23,7 @@ pub fn test_item_cloak_of_invisibility() {
     };
 
     let with_item_alone = &mut Character {
-        pos: PosAxial::new(-4, 0),
+        pos: PosAxial::new(-2, 1),
         team: CharacterTeam::Player,
         stamina_current: 10,
         equipped_items: vec![Item::CloakOfInvisibility],
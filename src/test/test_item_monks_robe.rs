@@ -4,14 +4,14 @@ use crate::{
     data_model::{Character, Condition},
     hex_grid::PosAxial,
     items::Item,
+    play_state::AbilityChoice,
     test::tools::{
-        play_card_with_inputs, resolve_remaining_abilities, single_movement_input,
+        ActionInput, play_card_with_inputs, resolve_remaining_abilities, single_movement_input,
         single_targeted_input,
     },
 };
 
-#[test]
-pub fn test_item_monks_robe() {
+fn step_next_to_other(choice: AbilityChoice) -> (Character, Character) {
     let pos_character = PosAxial::new(0, 0);
     let pos_other = PosAxial::new(0, 2);
     let pos_next_to_other = PosAxial::new(0, 1);
@@ -35,13 +35,28 @@ pub fn test_item_monks_robe() {
     )
     .unwrap();
 
-    resolve_remaining_abilities(
-        character,
-        other_characters,
-        single_targeted_input(pos_other).iter(),
-    )
-    .unwrap();
+    let inputs: Vec<ActionInput> = [ActionInput::AbilityChoice(choice)]
+        .into_iter()
+        .chain(match choice {
+            AbilityChoice::Use => single_targeted_input(pos_other),
+            _ => vec![],
+        })
+        .collect();
+    resolve_remaining_abilities(character, other_characters, inputs.iter()).unwrap();
 
+    (character.clone(), other_characters[0].clone())
+}
+
+#[test]
+pub fn test_item_monks_robe() {
+    let (character, other) = step_next_to_other(AbilityChoice::Use);
     assert_eq!(character.conditions.get(&Condition::Disarmed), 1);
-    assert_eq!(other_characters[0].conditions.get(&Condition::Stunned), 1);
+    assert_eq!(other.conditions.get(&Condition::Stunned), 1);
+}
+
+#[test]
+pub fn test_item_monks_robe_declined() {
+    let (character, other) = step_next_to_other(AbilityChoice::Decline);
+    assert_eq!(character.conditions.get(&Condition::Disarmed), 0);
+    assert_eq!(other.conditions.get(&Condition::Stunned), 0);
 }
@@ -4,10 +4,13 @@ use std::iter;
 use crate::{
     cards::Card,
     data_model::{Character, Condition},
+    event_log::EventLog,
     hex_grid::PosAxial,
     items::Item,
     play::end_turn,
-    test::tools::{play_card_with_inputs, resolve_remaining_abilities, single_targeted_input},
+    test::tools::{
+        play_card_with_inputs, registry, resolve_remaining_abilities, single_targeted_input,
+    },
 };
 
 #[test]
@@ -41,9 +44,9 @@ pub fn test_item_thorngrown_vest() {
     )
     .unwrap();
 
-    end_turn(item_attack);
-    end_turn(item_no_attack);
-    end_turn(no_item_no_attack);
+    end_turn(item_attack, registry(), &mut EventLog::default());
+    end_turn(item_no_attack, registry(), &mut EventLog::default());
+    end_turn(no_item_no_attack, registry(), &mut EventLog::default());
 
     resolve_remaining_abilities(item_attack, other_characters, iter::empty()).unwrap();
     resolve_remaining_abilities(item_no_attack, other_characters, iter::empty()).unwrap();
@@ -0,0 +1,62 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    cards::Card,
+    data_model::Character,
+    hex_grid::PosAxial,
+    items::Item,
+    single_out::single_out,
+    test::tools::{play_card_with_inputs, resolve_remaining_abilities, single_targeted_input},
+};
+
+#[test]
+pub fn test_item_volatile_core() {
+    let mut all_characters = [
+        Character {
+            health_current: 10,
+            health_max: 10,
+            stamina_current: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 1),
+            health_current: 2,
+            health_max: 10,
+            equipped_items: vec![Item::VolatileCore],
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 2),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 3),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+    ];
+
+    {
+        let (source, mut characters) = single_out(&mut all_characters, 0).unwrap();
+        play_card_with_inputs(
+            Card::Cut,
+            source,
+            &mut characters,
+            single_targeted_input(PosAxial::new(0, 1)).iter(),
+        )
+        .unwrap();
+    }
+    assert!(!all_characters[1].is_alive());
+
+    {
+        let (dead, mut characters) = single_out(&mut all_characters, 1).unwrap();
+        resolve_remaining_abilities(dead, &mut characters, iter::empty()).unwrap();
+    }
+    assert_eq!(all_characters[0].health_current, 7);
+    assert_eq!(all_characters[2].health_current, 7);
+    assert_eq!(all_characters[3].health_current, 10);
+}
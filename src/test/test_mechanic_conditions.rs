@@ -0,0 +1,247 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterTeam, Condition, Conditions},
+    deck::Deck,
+    hex_grid::PosAxial,
+    movement::MovementError,
+    play_state::PlayState,
+    test::tools::{
+        ScriptedInput, ScriptedInputEntry, TestSetupError, play_card_with_inputs, run_script,
+        single_movement_input, single_targeted_input,
+    },
+};
+
+const STARTING_HP: usize = 10;
+
+fn character_with_condition(condition: Condition, value: isize) -> Character {
+    Character {
+        stamina_current: 20,
+        health_current: STARTING_HP,
+        conditions: Conditions::default().with_incremented(condition, value),
+        ..Default::default()
+    }
+}
+
+fn adjacent_target() -> Vec<Character> {
+    vec![Character {
+        pos: PosAxial::new(0, 1),
+        health_current: STARTING_HP,
+        ..Default::default()
+    }]
+}
+
+#[test]
+pub fn test_mechanic_stunned_drops_actions() {
+    let character = &mut character_with_condition(Condition::Stunned, 1);
+    let other_characters = &mut adjacent_target();
+
+    play_card_with_inputs(Card::Cut, character, other_characters, iter::empty()).unwrap();
+    play_card_with_inputs(Card::Adrenaline, character, other_characters, iter::empty()).unwrap();
+
+    assert_eq!(other_characters[0].health_current, STARTING_HP);
+    assert_eq!(character.conditions.get(&Condition::Strong), 0);
+}
+
+#[test]
+pub fn test_mechanic_stunned_loses_turn() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Adrenaline]),
+            ..Default::default()
+        },
+        Character {
+            team: CharacterTeam::Monster,
+            pos: PosAxial::new(0, 1),
+            stamina_current: 10,
+            conditions: Conditions::default().with_incremented(Condition::Stunned, 1),
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::EndTurn,
+        // The monster is Stunned, so its turn is skipped and this card is played by the player
+        ScriptedInputEntry::PlayCard(0),
+    ]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 2);
+    assert_eq!(characters[1].conditions.get(&Condition::Strong), 0);
+    assert_eq!(characters[1].conditions.get(&Condition::Stunned), 0);
+}
+
+#[test]
+pub fn test_mechanic_stunned_loses_first_turn() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            stamina_current: 10,
+            conditions: Conditions::default().with_incremented(Condition::Stunned, 1),
+            deck: Deck::with_hand([Card::Adrenaline]),
+            ..Default::default()
+        },
+        Character {
+            team: CharacterTeam::Monster,
+            pos: PosAxial::new(0, 1),
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Adrenaline]),
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    // The player is Stunned, so the first turn is skipped and this card is played by the monster
+    let mut input = ScriptedInput::new([ScriptedInputEntry::PlayCard(0)]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(play_state.has_turn(), 1);
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 0);
+    assert_eq!(characters[0].conditions.get(&Condition::Stunned), 0);
+    assert_eq!(characters[1].conditions.get(&Condition::Strong), 2);
+}
+
+#[test]
+pub fn test_mechanic_disarmed() {
+    let character = &mut character_with_condition(Condition::Disarmed, 1);
+    let other_characters = &mut adjacent_target();
+
+    // The attack is dropped entirely, so no target is asked for
+    play_card_with_inputs(Card::Cut, character, other_characters, iter::empty()).unwrap();
+    assert_eq!(other_characters[0].health_current, STARTING_HP);
+
+    // Non-attack effects are still applied
+    play_card_with_inputs(Card::Adrenaline, character, other_characters, iter::empty()).unwrap();
+    assert_eq!(character.conditions.get(&Condition::Strong), 2);
+
+    // Even when Fury turns the attack into an area effect
+    play_card_with_inputs(Card::Fury, character, other_characters, iter::empty()).unwrap();
+    play_card_with_inputs(Card::Cut, character, other_characters, iter::empty()).unwrap();
+    assert_eq!(other_characters[0].health_current, STARTING_HP);
+}
+
+#[test]
+pub fn test_mechanic_immobilized() {
+    let character = &mut Character {
+        stamina_current: 20,
+        ..Default::default()
+    };
+
+    play_card_with_inputs(Card::Calm, character, &mut vec![], iter::empty()).unwrap();
+    let result = play_card_with_inputs(
+        Card::Step,
+        character,
+        &mut vec![],
+        single_movement_input(vec![PosAxial::new(0, 1)]).iter(),
+    );
+
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(MovementError::Immobilized))
+    ));
+    assert_eq!(character.pos, PosAxial::default());
+}
+
+#[test]
+pub fn test_mechanic_slow_and_fast() {
+    let path = vec![
+        PosAxial::new(0, 1),
+        PosAxial::new(0, 2),
+        PosAxial::new(0, 3),
+    ];
+
+    // Step moves 2 spaces
+    let result = play_card_with_inputs(
+        Card::Step,
+        &mut character_with_condition(Condition::Slow, 1),
+        &mut vec![],
+        single_movement_input(path[..2].to_vec()).iter(),
+    );
+    assert!(matches!(
+        result,
+        Err(TestSetupError::InvalidMovement(
+            MovementError::PathTooLong { spaces: 1, .. }
+        ))
+    ));
+
+    let fast = &mut character_with_condition(Condition::Fast, 1);
+    play_card_with_inputs(
+        Card::Step,
+        fast,
+        &mut vec![],
+        single_movement_input(path.clone()).iter(),
+    )
+    .unwrap();
+    assert_eq!(fast.pos, PosAxial::new(0, 3));
+}
+
+#[test]
+pub fn test_mechanic_disarmed_allows_non_attacks() {
+    let character = &mut character_with_condition(Condition::Disarmed, 1);
+    let other_characters = &mut adjacent_target();
+    let inputs = [
+        single_movement_input(vec![]),
+        single_targeted_input(PosAxial::new(0, 1)),
+    ]
+    .concat();
+
+    play_card_with_inputs(Card::Shove, character, other_characters, inputs.iter()).unwrap();
+
+    assert_eq!(other_characters[0].pos, PosAxial::new(0, 3));
+}
+
+#[test]
+pub fn test_mechanic_immobilized_cancel_movement() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            stamina_current: 10,
+            conditions: Conditions::default().with_incremented(Condition::Immobilized, 2),
+            deck: Deck::with_hand([Card::Step, Card::Adrenaline]),
+            ..Default::default()
+        },
+        Character {
+            team: CharacterTeam::Monster,
+            pos: PosAxial::new(0, 3),
+            health_current: STARTING_HP,
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([
+        ScriptedInputEntry::PlayCard(0),
+        // The movement can not be made, so it is canceled and the turn goes on
+        ScriptedInputEntry::Cancel,
+        ScriptedInputEntry::PlayCard(0),
+    ]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(characters[0].pos, PosAxial::default());
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 2);
+    assert!(characters[0].deck.hand.is_empty());
+}
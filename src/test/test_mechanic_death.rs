@@ -0,0 +1,218 @@
+#![cfg(test)]
+use std::iter;
+
+use crate::{
+    board::Board,
+    cards::Card,
+    data_model::{Character, CharacterId, CharacterTeam, Condition, Conditions, Death},
+    deck::Deck,
+    hex_grid::PosAxial,
+    play_state::{DuplicateCharacterId, PlayState, start_encounter},
+    test::tools::{
+        ScriptedInput, ScriptedInputEntry, end_and_begin_turn, play_card_with_inputs, run_script,
+        single_movement_input, single_targeted_input,
+    },
+};
+
+#[test]
+pub fn test_mechanic_death_killer_and_overkill() {
+    let character = &mut Character {
+        id: CharacterId(1),
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        id: CharacterId(2),
+        pos: PosAxial::new(0, 1),
+        health_current: 2,
+        health_max: 10,
+        ..Default::default()
+    }];
+
+    play_card_with_inputs(
+        Card::Strike,
+        character,
+        other_characters,
+        single_targeted_input(PosAxial::new(0, 1)).iter(),
+    )
+    .unwrap();
+
+    assert_eq!(
+        other_characters[0].death,
+        Some(Death {
+            killer: Some(CharacterId(1)),
+            overkill: 3,
+        })
+    );
+    assert!(character.is_alive());
+}
+
+#[test]
+pub fn test_mechanic_death_without_killer() {
+    let character = &mut Character {
+        health_current: 2,
+        health_max: 10,
+        conditions: Conditions::default().with_incremented(Condition::Poison, 3),
+        ..Default::default()
+    };
+    end_and_begin_turn(character);
+    assert_eq!(
+        character.death,
+        Some(Death {
+            killer: None,
+            overkill: 1,
+        })
+    );
+
+    // A character already at 0 health is not killed again
+    let character = &mut Character::default();
+    end_and_begin_turn(character);
+    assert!(character.is_alive());
+}
+
+#[test]
+pub fn test_mechanic_death_dead_characters_are_ignored() {
+    let character = &mut Character {
+        stamina_current: 10,
+        ..Default::default()
+    };
+    let other_characters = &mut vec![Character {
+        pos: PosAxial::new(0, 1),
+        health_current: 5,
+        health_max: 10,
+        death: Some(Death {
+            killer: None,
+            overkill: 0,
+        }),
+        ..Default::default()
+    }];
+
+    // The dead character's hex can be entered, and area effects pass over it
+    play_card_with_inputs(Card::Whirlwind, character, other_characters, iter::empty()).unwrap();
+    assert_eq!(other_characters[0].health_current, 5);
+    play_card_with_inputs(
+        Card::Step,
+        character,
+        other_characters,
+        single_movement_input(vec![PosAxial::new(0, 1)]).iter(),
+    )
+    .unwrap();
+    assert_eq!(character.pos, PosAxial::new(0, 1));
+}
+
+#[test]
+pub fn test_mechanic_death_skipped_in_turn_order() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            stamina_current: 10,
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 1),
+            stamina_current: 10,
+            death: Some(Death {
+                killer: None,
+                overkill: 0,
+            }),
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 2),
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Adrenaline]),
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input =
+        ScriptedInput::new([ScriptedInputEntry::EndTurn, ScriptedInputEntry::PlayCard(0)]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert_eq!(characters[1].conditions.get(&Condition::Strong), 0);
+    assert_eq!(characters[2].conditions.get(&Condition::Strong), 2);
+}
+
+#[test]
+pub fn test_mechanic_death_ends_turn() {
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            health_current: 1,
+            health_max: 10,
+            stamina_current: 10,
+            deck: Deck::with_hand([Card::Fury, Card::Cut]),
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 1),
+            health_current: 10,
+            health_max: 10,
+            stamina_current: 10,
+            conditions: Conditions::default().with_incremented(Condition::Retaliate, 1),
+            deck: Deck::with_hand([Card::Adrenaline]),
+            ..Default::default()
+        },
+        // Keeps the encounter going after the first player dies
+        Character {
+            team: CharacterTeam::Player,
+            pos: PosAxial::new(0, 5),
+            health_current: 10,
+            health_max: 10,
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+    let mut input = ScriptedInput::new([
+        // The player dies from Retaliate, so the next card is played by the monster
+        ScriptedInputEntry::PlayCard(0),
+        ScriptedInputEntry::PlayCard(0),
+        ScriptedInputEntry::PlayCard(0),
+    ]);
+
+    run_script(
+        &mut play_state,
+        &mut input,
+        &Board::default(),
+        &mut characters,
+    )
+    .unwrap();
+
+    assert!(!characters[0].is_alive());
+    assert_eq!(characters[0].conditions.get(&Condition::Strong), 0);
+    assert_eq!(characters[1].conditions.get(&Condition::Strong), 2);
+}
+
+#[test]
+pub fn test_mechanic_death_requires_unique_ids() {
+    // Kills are attributed by id, so characters left at the default id can not be told apart
+    let mut characters = [
+        Character {
+            team: CharacterTeam::Player,
+            deck: Deck::new([Card::Cut; 6]),
+            ..Default::default()
+        },
+        Character {
+            pos: PosAxial::new(0, 1),
+            ..Default::default()
+        },
+    ];
+    let mut play_state = PlayState::default();
+
+    assert_eq!(
+        start_encounter(&mut play_state, &mut characters),
+        Err(DuplicateCharacterId(CharacterId(0)))
+    );
+    assert!(characters[0].deck.hand.is_empty());
+
+    characters[1].id = CharacterId(1);
+    assert_eq!(start_encounter(&mut play_state, &mut characters), Ok(()));
+    assert_eq!(characters[0].deck.hand.len(), 5);
+}
@@ -1,22 +1,60 @@
 #![cfg(test)]
 
+use std::{collections::VecDeque, sync::LazyLock};
+
 use crate::{
+    board::Board,
     cards::Card,
-    data_model::{Ability, Action, Character},
+    data_model::{
+        Ability, Action, ActionMovement, ActionOnSelf, ActionTargeted, Character, CharacterId,
+        CharacterTeam,
+    },
+    encounter::EncounterOutcome,
+    event_log::EventLog,
+    game_event::Observer,
     hex_grid::PosAxial,
-    play::{begin_turn, end_turn, play_card_unchecked},
-    pop_ability::{PopAbilityResult, pop_ability},
+    movement::MovementError,
+    play::{PlayCardError, begin_turn, end_turn, play_card},
+    play_state::{
+        AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn, PlayState, StepError,
+        step_play_state,
+    },
+    pop_ability::{chosen_actions, map_actions},
+    precondition::optional_precondition_is_met,
+    registry::Registry,
     resolve_action::{
         ActionInputMovement, ActionInputOnSelf, ActionInputTargeted, resolve_action_movement,
         resolve_action_on_self, resolve_action_targeted,
     },
+    targeting::TargetError,
 };
 
+// The built-in cards and items, loaded once for all tests.
+pub fn registry() -> &'static Registry {
+    static REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::builtin);
+    &REGISTRY
+}
+
+// A character at full health with enough stamina for a few cards, and otherwise the defaults.
+pub fn character(id: usize, team: CharacterTeam, pos: PosAxial, health: usize) -> Character {
+    Character {
+        id: CharacterId(id),
+        team,
+        pos,
+        health_current: health,
+        health_max: health,
+        stamina_current: 10,
+        stamina_max: 10,
+        ..Default::default()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ActionInput {
     OnSelf(ActionInputOnSelf),
     Targeted(ActionInputTargeted),
     Movement(ActionInputMovement),
+    AbilityChoice(AbilityChoice),
 }
 
 pub fn single_targeted_input(target: PosAxial) -> Vec<ActionInput> {
@@ -42,6 +80,13 @@ pub enum TestSetupError {
         card: Card,
         remaining_abilities: Vec<Ability>,
     },
+    TryingToResolveAbilityWithoutChoice {
+        ability: Ability,
+    },
+    InvalidAbilityChoice(AbilityChoice),
+    InvalidMovement(MovementError),
+    InvalidTarget(TargetError),
+    CanNotPlayCard(PlayCardError),
 }
 
 pub fn resolve_remaining_abilities<'a, C, I>(
@@ -54,10 +99,51 @@ where
     for<'b> &'b mut C: IntoIterator<Item = &'b mut Character>,
     I: Iterator<Item = &'a ActionInput>,
 {
-    resolve_abilities(character, characters, inputs, usize::MAX)
+    resolve_abilities(&Board::default(), character, characters, inputs, usize::MAX)
+}
+
+enum PopAbilityResult {
+    NoRemainingAbilities,
+    NextAbilityDoesNotSatisfyPrecondition,
+    Ability(Ability),
+}
+
+// Resolves the abilities of a single character directly, without the trigger stack of PlayState.
+fn pop_ability<'a, C>(source_character: &'a mut Character, characters: C) -> PopAbilityResult
+where
+    C: IntoIterator<Item = &'a Character>,
+{
+    match source_character.remaining_abilities.pop() {
+        Some(ability)
+            if optional_precondition_is_met(
+                ability.precondition.as_ref(),
+                characters,
+                source_character,
+            ) =>
+        {
+            PopAbilityResult::Ability(ability)
+        }
+        Some(_) => PopAbilityResult::NextAbilityDoesNotSatisfyPrecondition,
+        None => PopAbilityResult::NoRemainingAbilities,
+    }
+}
+
+// Optional and choose one abilities take the next input as their choice.
+fn choose_actions<'a, I>(ability: Ability, inputs: &mut I) -> Result<Vec<Action>, TestSetupError>
+where
+    I: Iterator<Item = &'a ActionInput>,
+{
+    if ability.kind.is_mandatory() {
+        return Ok(ability.actions);
+    }
+    let Some(ActionInput::AbilityChoice(choice)) = inputs.next() else {
+        return Err(TestSetupError::TryingToResolveAbilityWithoutChoice { ability });
+    };
+    chosen_actions(&ability, *choice).ok_or(TestSetupError::InvalidAbilityChoice(*choice))
 }
 
 pub fn resolve_abilities<'a, C, I>(
+    board: &Board,
     character: &mut Character,
     characters: &mut C,
     inputs: I,
@@ -68,6 +154,8 @@ where
     for<'b> &'b mut C: IntoIterator<Item = &'b mut Character>,
     I: Iterator<Item = &'a ActionInput>,
 {
+    // Scripted test setups are not replayed, so the events are not kept.
+    let log = &mut EventLog::default();
     let mut inputs = inputs;
     let mut count = 0;
     while count < ability_limit {
@@ -77,12 +165,20 @@ where
                 break;
             }
             PopAbilityResult::NextAbilityDoesNotSatisfyPrecondition => {}
-            PopAbilityResult::Actions { actions } => {
-                for action in actions {
+            PopAbilityResult::Ability(ability) => {
+                let actions = choose_actions(ability, &mut inputs)?;
+                for action in map_actions(character, actions.into_iter()) {
                     let action_clone = action.clone();
                     match action {
                         Action::OnSelf(action) => {
-                            resolve_action_on_self(&action, character, characters);
+                            resolve_action_on_self(
+                                &action,
+                                board,
+                                character,
+                                characters,
+                                registry(),
+                                log,
+                            );
                         }
                         Action::Targeted(action) => {
                             let input = match inputs.next() {
@@ -103,7 +199,16 @@ where
                                     );
                                 }
                             };
-                            resolve_action_targeted(&action, input, character, characters);
+                            resolve_action_targeted(
+                                &action,
+                                input,
+                                board,
+                                character,
+                                characters,
+                                registry(),
+                                log,
+                            )
+                            .map_err(TestSetupError::InvalidTarget)?;
                         }
                         Action::Movement(action) => {
                             let input = match inputs.next() {
@@ -124,7 +229,16 @@ where
                                     );
                                 }
                             };
-                            resolve_action_movement(&action, input, character);
+                            resolve_action_movement(
+                                &action,
+                                input,
+                                board,
+                                character,
+                                characters,
+                                registry(),
+                                log,
+                            )
+                            .map_err(TestSetupError::InvalidMovement)?;
                         }
                     }
                 }
@@ -140,6 +254,21 @@ pub fn play_card_with_inputs<'a, C, I>(
     characters: &mut C,
     inputs: I,
 ) -> Result<(), TestSetupError>
+where
+    for<'b> &'b C: IntoIterator<Item = &'b Character>,
+    for<'b> &'b mut C: IntoIterator<Item = &'b mut Character>,
+    I: Iterator<Item = &'a ActionInput>,
+{
+    play_card_with_inputs_on_board(card, &Board::default(), character, characters, inputs)
+}
+
+pub fn play_card_with_inputs_on_board<'a, C, I>(
+    card: Card,
+    board: &Board,
+    character: &mut Character,
+    characters: &mut C,
+    inputs: I,
+) -> Result<(), TestSetupError>
 where
     for<'b> &'b C: IntoIterator<Item = &'b Character>,
     for<'b> &'b mut C: IntoIterator<Item = &'b mut Character>,
@@ -151,11 +280,174 @@ where
             remaining_abilities: character.remaining_abilities.clone(),
         });
     }
-    let abilities_to_resolve = play_card_unchecked(character, card.data());
-    resolve_abilities(character, characters, inputs, abilities_to_resolve)
+    let card_data = registry()
+        .card(&card)
+        .expect("The card is built in")
+        .clone();
+    let abilities_to_resolve =
+        play_card(character, card_data, registry()).map_err(TestSetupError::CanNotPlayCard)?;
+    resolve_abilities(board, character, characters, inputs, abilities_to_resolve)
 }
 
 pub fn end_and_begin_turn(character: &mut Character) {
-    end_turn(character);
-    begin_turn(character);
+    let log = &mut EventLog::default();
+    end_turn(character, registry(), log);
+    begin_turn(character, registry(), log);
+}
+
+#[derive(Debug)]
+pub enum ScriptedInputEntry {
+    PlayCard(usize), // Index into the hand
+    EndTurn,
+    Action(ActionInput),
+    Cancel,
+}
+
+// Feeds the engine a fixed sequence of inputs. On-self actions are confirmed automatically,
+// unless the next entry is Cancel.
+#[derive(Default)]
+pub struct ScriptedInput {
+    entries: VecDeque<ScriptedInputEntry>,
+    starved: bool,
+}
+
+impl ScriptedInput {
+    pub fn new<E>(entries: E) -> Self
+    where
+        E: IntoIterator<Item = ScriptedInputEntry>,
+    {
+        Self {
+            entries: entries.into_iter().collect(),
+            starved: false,
+        }
+    }
+
+    fn poll<T>(&mut self, f: impl FnOnce(&ScriptedInputEntry) -> Option<T>) -> PendingInput<T> {
+        match self.entries.front().and_then(f) {
+            Some(input) => {
+                self.entries.pop_front();
+                PendingInput::Some(input)
+            }
+            None => {
+                self.starved = self.entries.is_empty();
+                PendingInput::Pending
+            }
+        }
+    }
+
+    fn poll_cancelable<T>(
+        &mut self,
+        f: impl FnOnce(&ScriptedInputEntry) -> Option<T>,
+    ) -> PendingInput<Cancelable<T>> {
+        self.poll(|entry| match entry {
+            ScriptedInputEntry::Cancel => Some(Cancelable::Canceled),
+            entry => f(entry).map(Cancelable::Some),
+        })
+    }
+}
+
+fn targeted_input(entry: &ScriptedInputEntry) -> Option<ActionInputTargeted> {
+    match entry {
+        ScriptedInputEntry::Action(ActionInput::Targeted(input)) => Some(input.clone()),
+        _ => None,
+    }
+}
+
+fn movement_input(entry: &ScriptedInputEntry) -> Option<ActionInputMovement> {
+    match entry {
+        ScriptedInputEntry::Action(ActionInput::Movement(input)) => Some(input.clone()),
+        _ => None,
+    }
+}
+
+impl Input for ScriptedInput {
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Some(ActionInputOnSelf {})
+    }
+    fn poll_action_input_targeted(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        self.poll(targeted_input)
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        self.poll(movement_input)
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        _: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        match self.entries.front() {
+            Some(ScriptedInputEntry::Cancel) => {
+                self.entries.pop_front();
+                PendingInput::Some(Cancelable::Canceled)
+            }
+            _ => PendingInput::Some(Cancelable::Some(ActionInputOnSelf {})),
+        }
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        self.poll_cancelable(targeted_input)
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        self.poll_cancelable(movement_input)
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        self.poll(|entry| match entry {
+            ScriptedInputEntry::PlayCard(index) => Some(PlayCardOrEndTurn::PlayCard(*index)),
+            ScriptedInputEntry::EndTurn => Some(PlayCardOrEndTurn::EndTurn),
+            _ => None,
+        })
+    }
+    fn poll_ability_choice(&mut self, _: &Ability) -> PendingInput<AbilityChoice> {
+        self.poll(|entry| match entry {
+            ScriptedInputEntry::Action(ActionInput::AbilityChoice(choice)) => Some(*choice),
+            _ => None,
+        })
+    }
+}
+
+// Steps the engine until every scripted input has been consumed and the engine is waiting for more,
+// or the encounter has ended.
+pub fn run_script(
+    play_state: &mut PlayState,
+    input: &mut ScriptedInput,
+    board: &Board,
+    characters: &mut [Character],
+) -> Result<(), StepError> {
+    run_script_with_observer(
+        play_state,
+        input,
+        board,
+        characters,
+        &mut EventLog::default(),
+    )
+}
+
+pub fn run_script_with_observer(
+    play_state: &mut PlayState,
+    input: &mut ScriptedInput,
+    board: &Board,
+    characters: &mut [Character],
+    observer: &mut dyn Observer,
+) -> Result<(), StepError> {
+    const MAX_STEPS: usize = 1000;
+    for _ in 0..MAX_STEPS {
+        if input.starved || *play_state.outcome() != EncounterOutcome::InProgress {
+            return Ok(());
+        }
+        step_play_state(play_state, input, board, characters, registry(), observer)?;
+    }
+    panic!(
+        "The engine did not consume the scripted input within {} steps",
+        MAX_STEPS
+    );
 }
@@ -0,0 +1,444 @@
+use std::{
+    io::{self, Write},
+    path::Path,
+    time::Duration,
+};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEventKind},
+    execute, queue,
+    style::Print,
+    terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen},
+};
+
+use crate::{
+    board_svg::export,
+    board_view::{BoardView, character_name},
+    data_model::{Ability, AbilityKind, Action, ActionMovement, ActionOnSelf, ActionTargeted},
+    encounter::{Encounter, EncounterOutcome},
+    game_event::GameEvent,
+    game_loop::FrontEnd,
+    hex_grid::{PosAxial, PosOddQHex},
+    play_state::{AbilityChoice, Cancelable, Input, PendingInput, PlayCardOrEndTurn},
+    registry::Registry,
+    render_hex_grid::visible_char_count,
+    resolve_action::{ActionInputMovement, ActionInputOnSelf, ActionInputTargeted},
+};
+
+// How long a poll waits for a key before the input is left pending.
+const KEY_TIMEOUT: Duration = Duration::from_millis(50);
+const LOG_LINES: usize = 10;
+const EXPORT_PATH: &str = "board.svg";
+
+// A full-screen front end. Hexes are chosen with a cursor moved by the arrow keys, everything else
+// with single keys. Every poll waits briefly for one key, so an input stays pending until the key
+// that completes it is pressed.
+pub struct Tui {
+    encounter: Encounter, // As of the last show.
+    registry: Registry,
+    cursor: PosAxial,
+    path: Vec<PosAxial>, // Of the movement being chosen.
+    log: Vec<String>,
+    prompt: String,
+    message: String, // The last error, or the outcome.
+    quit: bool,
+    redraw: bool,
+}
+
+impl Tui {
+    // The terminal is restored when the Tui is dropped.
+    pub fn new() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Self {
+            encounter: Encounter::default(),
+            registry: Registry::default(),
+            cursor: PosAxial::default(),
+            path: Vec::new(),
+            log: Vec::new(),
+            prompt: String::new(),
+            message: String::new(),
+            quit: false,
+            redraw: true,
+        })
+    }
+
+    // Draws the screen if anything changed, and returns the next key pressed, if any. Moving the
+    // cursor and quitting are handled here.
+    fn poll_key(&mut self, prompt: &str) -> Option<KeyCode> {
+        if self.prompt != prompt {
+            self.prompt = prompt.into();
+            self.redraw = true;
+        }
+        if self.redraw {
+            if self.draw().is_err() {
+                self.quit = true;
+                return None;
+            }
+            self.redraw = false;
+        }
+        let event = match event::poll(KEY_TIMEOUT) {
+            Ok(true) => event::read(),
+            Ok(false) => return None,
+            Err(err) => Err(err),
+        };
+        let key = match event {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => key.code,
+            Ok(Event::Resize(..)) => {
+                self.redraw = true;
+                return None;
+            }
+            Ok(_) => return None,
+            Err(_) => {
+                self.quit = true;
+                return None;
+            }
+        };
+        self.redraw = true;
+        self.message.clear();
+        match key {
+            KeyCode::Char('q') => self.quit = true,
+            KeyCode::Char('x') => {
+                self.message = match export(&self.board_view(), Path::new(EXPORT_PATH)) {
+                    Ok(()) => format!("Exported the board to {}", EXPORT_PATH),
+                    Err(err) => err,
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_cursor(-1, 0),
+            KeyCode::Down | KeyCode::Char('j') => self.move_cursor(1, 0),
+            KeyCode::Left | KeyCode::Char('h') => self.move_cursor(0, -1),
+            KeyCode::Right | KeyCode::Char('l') => self.move_cursor(0, 1),
+            key => return Some(key),
+        }
+        None
+    }
+
+    // In the odd-q coordinates of the rendered board, so that the cursor moves the way it looks.
+    fn move_cursor(&mut self, delta_r: isize, delta_q: isize) {
+        let pos = self.cursor.to_odd_q();
+        let moved = PosOddQHex::new(pos.r + delta_r, pos.q + delta_q).to_axial();
+        if self.encounter.board.contains(&moved) {
+            self.cursor = moved;
+        }
+    }
+
+    fn poll_targeted(&mut self, cancelable: bool) -> Option<Cancelable<ActionInputTargeted>> {
+        let prompt = with_cancel(
+            "Move the cursor to a target.\nEnter: choose it.",
+            cancelable,
+        );
+        match self.poll_key(&prompt)? {
+            KeyCode::Enter | KeyCode::Char(' ') => Some(Cancelable::Some(ActionInputTargeted {
+                target: self.cursor.clone(),
+            })),
+            KeyCode::Esc if cancelable => Some(Cancelable::Canceled),
+            _ => None,
+        }
+    }
+
+    fn poll_movement(&mut self, cancelable: bool) -> Option<Cancelable<ActionInputMovement>> {
+        let prompt = with_cancel(
+            "Space: add the cursor to the path, or remove it again.\nBackspace: remove the last hex.\n\
+             Enter: move along the path, or stay if it is empty.",
+            cancelable,
+        );
+        match self.poll_key(&prompt)? {
+            KeyCode::Char(' ') => {
+                if self.path.last() == Some(&self.cursor) {
+                    self.path.pop();
+                } else {
+                    self.path.push(self.cursor.clone());
+                }
+                None
+            }
+            KeyCode::Backspace => {
+                self.path.pop();
+                None
+            }
+            KeyCode::Enter => Some(Cancelable::Some(ActionInputMovement {
+                path: std::mem::take(&mut self.path),
+            })),
+            KeyCode::Esc if !self.path.is_empty() => {
+                self.path.clear();
+                None
+            }
+            KeyCode::Esc if cancelable => Some(Cancelable::Canceled),
+            _ => None,
+        }
+    }
+
+    // The area of a targeted action is previewed around the cursor.
+    fn board_view(&self) -> BoardView<'_> {
+        let board_view = BoardView::of_encounter(&self.encounter);
+        let board_view = match self.encounter.play_state.pending_action() {
+            Some(Action::Targeted(action)) => {
+                board_view.with_area_preview(&action.effects, &self.cursor)
+            }
+            _ => board_view,
+        };
+        board_view.with_path(&self.path)
+    }
+
+    fn draw(&self) -> io::Result<()> {
+        let board = self
+            .board_view()
+            .with_colors(true)
+            .with_cursor(self.cursor.clone())
+            .render();
+
+        let panel_x = board.lines().map(visible_char_count).max().unwrap_or(0) + 2;
+        let (width, height) = terminal::size()?;
+        let panel_width = (width as usize).saturating_sub(panel_x);
+        let mut stdout = io::stdout();
+        queue!(stdout, Clear(ClearType::All))?;
+        for (y, line) in board.lines().enumerate().take(height as usize) {
+            queue!(stdout, MoveTo(0, y as u16), Print(line))?;
+        }
+        for (y, line) in self.panel().iter().enumerate().take(height as usize) {
+            let line: String = line.chars().take(panel_width).collect();
+            queue!(stdout, MoveTo(panel_x as u16, y as u16), Print(line))?;
+        }
+        stdout.flush()
+    }
+
+    // The lines next to the board: the hand, the turn order, the characters, what to do, and the
+    // latest events.
+    fn panel(&self) -> Vec<String> {
+        let play_state = &self.encounter.play_state;
+        let characters = &self.encounter.characters;
+        let mut lines = Vec::new();
+        if let Some(character) = characters.get(play_state.has_turn()) {
+            lines.push(format!(
+                "Round {}, turn of {}",
+                play_state.round() + 1,
+                character_name(character)
+            ));
+            lines.push(String::new());
+            lines.push("Hand".into());
+            for (index, card) in character.deck.hand.iter().enumerate() {
+                let key = card_key(index).map(String::from).unwrap_or_default();
+                let cost = match self.registry.card(card) {
+                    Some(card_data) => card_data.stamina_cost.to_string(),
+                    None => "unknown".into(),
+                };
+                lines.push(format!("  {} {} ({})", key, card, cost));
+            }
+        }
+        if !play_state.turn_order().is_empty() {
+            lines.push(String::new());
+            lines.push("Turn order".into());
+            let names: Vec<String> = play_state
+                .turn_order()
+                .iter()
+                .filter_map(|index| characters.get(*index))
+                .filter(|character| character.is_alive())
+                .map(character_name)
+                .collect();
+            lines.push(format!("  {}", names.join(", ")));
+        }
+        lines.push(String::new());
+        lines.push("Characters".into());
+        for character in characters.iter().filter(|character| character.is_alive()) {
+            let conditions: String = character
+                .conditions
+                .active()
+                .iter()
+                .map(|(condition, value)| format!(" {:?} {}", condition, value))
+                .collect();
+            lines.push(format!(
+                "  {}: {}/{} health, {}/{} stamina{}",
+                character_name(character),
+                character.health_current,
+                character.health_max,
+                character.stamina_current,
+                character.stamina_max,
+                conditions
+            ));
+        }
+        lines.push(String::new());
+        lines.extend(self.prompt.lines().map(String::from));
+        lines.push(format!(
+            "Arrows or hjkl: move the cursor. x: export to {}. q: quit.",
+            EXPORT_PATH
+        ));
+        if !self.message.is_empty() {
+            lines.push(self.message.clone());
+        }
+        lines.push(String::new());
+        lines.push("Log".into());
+        let skipped = self.log.len().saturating_sub(LOG_LINES);
+        lines.extend(self.log[skipped..].iter().map(|line| format!("  {}", line)));
+        lines
+    }
+}
+
+impl Drop for Tui {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn with_cancel(prompt: &str, cancelable: bool) -> String {
+    if cancelable {
+        format!("{}\nEsc: cancel the ability.", prompt)
+    } else {
+        prompt.into()
+    }
+}
+
+// Cards are played with the digit keys, 1 being the first card and 0 the tenth.
+fn card_key(index: usize) -> Option<char> {
+    char::from_digit(((index + 1) % 10) as u32, 10).filter(|_| index < 10)
+}
+
+fn key_index(key: KeyCode) -> Option<usize> {
+    let KeyCode::Char(c) = key else {
+        return None;
+    };
+    let digit = c.to_digit(10)? as usize;
+    Some((digit + 9) % 10)
+}
+
+fn non_cancelable<T>(input: Option<Cancelable<T>>) -> PendingInput<T> {
+    match input {
+        Some(Cancelable::Some(input)) => PendingInput::Some(input),
+        _ => PendingInput::Pending,
+    }
+}
+
+fn pending<T>(input: Option<T>) -> PendingInput<T> {
+    match input {
+        Some(input) => PendingInput::Some(input),
+        None => PendingInput::Pending,
+    }
+}
+
+impl Input for Tui {
+    // Nothing to choose
+    fn poll_action_input_on_self(&mut self, _: &ActionOnSelf) -> PendingInput<ActionInputOnSelf> {
+        PendingInput::Some(ActionInputOnSelf {})
+    }
+    fn poll_action_input_targeted(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<ActionInputTargeted> {
+        let input = self.poll_targeted(false);
+        non_cancelable(input)
+    }
+    fn poll_action_input_movement(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<ActionInputMovement> {
+        let input = self.poll_movement(false);
+        non_cancelable(input)
+    }
+    fn poll_action_input_on_self_cancelable(
+        &mut self,
+        _: &ActionOnSelf,
+    ) -> PendingInput<Cancelable<ActionInputOnSelf>> {
+        let key = self.poll_key("Enter: use the ability.\nEsc: cancel it.");
+        pending(match key {
+            Some(KeyCode::Enter | KeyCode::Char(' ')) => {
+                Some(Cancelable::Some(ActionInputOnSelf {}))
+            }
+            Some(KeyCode::Esc) => Some(Cancelable::Canceled),
+            _ => None,
+        })
+    }
+    fn poll_action_input_targeted_cancelable(
+        &mut self,
+        _: &ActionTargeted,
+    ) -> PendingInput<Cancelable<ActionInputTargeted>> {
+        let input = self.poll_targeted(true);
+        pending(input)
+    }
+    fn poll_action_input_movement_cancelable(
+        &mut self,
+        _: &ActionMovement,
+    ) -> PendingInput<Cancelable<ActionInputMovement>> {
+        let input = self.poll_movement(true);
+        pending(input)
+    }
+    fn poll_play_card_or_end_turn(&mut self) -> PendingInput<PlayCardOrEndTurn> {
+        let key = self.poll_key("Digit: play the card.\ne: end the turn.");
+        pending(match key {
+            Some(KeyCode::Char('e')) => Some(PlayCardOrEndTurn::EndTurn),
+            Some(key) => key_index(key).map(PlayCardOrEndTurn::PlayCard),
+            None => None,
+        })
+    }
+    fn poll_ability_choice(&mut self, ability: &Ability) -> PendingInput<AbilityChoice> {
+        let prompt = match &ability.kind {
+            AbilityKind::ChooseOne(options) => {
+                format!("Digit: choose one of {} options.", options.len())
+            }
+            _ => "y: use the optional ability.\nn: skip it.".into(),
+        };
+        let key = self.poll_key(&prompt);
+        pending(match key {
+            Some(KeyCode::Char('y')) => Some(AbilityChoice::Use),
+            Some(KeyCode::Char('n')) => Some(AbilityChoice::Decline),
+            Some(key) => key_index(key).map(AbilityChoice::Choose),
+            None => None,
+        })
+    }
+}
+
+impl FrontEnd for Tui {
+    fn quit(&self) -> bool {
+        self.quit
+    }
+    fn show(&mut self, encounter: &Encounter, registry: &Registry, events: &[GameEvent]) {
+        let play_state = &encounter.play_state;
+        // A new input starts at the character it is for
+        let new_input = self.encounter.characters.is_empty()
+            || play_state.acting() != self.encounter.play_state.acting()
+            || play_state.pending_action() != self.encounter.play_state.pending_action();
+        if new_input {
+            self.path.clear();
+            if let Some(character) = encounter.characters.get(play_state.acting()) {
+                self.cursor = character.pos.clone();
+            }
+        }
+        self.encounter = encounter.clone();
+        self.registry = registry.clone();
+        self.log
+            .extend(events.iter().map(|event| format!("{:?}", event)));
+        self.redraw = true;
+    }
+    fn show_error(&mut self, message: &str) {
+        self.message = message.into();
+        self.redraw = true;
+    }
+    fn show_outcome(&mut self, outcome: &EncounterOutcome) {
+        self.prompt = "Press any key to leave.".into();
+        self.message = format!("{:?}", outcome);
+        if self.draw().is_err() {
+            return;
+        }
+        while let Ok(event) = event::read() {
+            if matches!(event, Event::Key(key) if key.kind == KeyEventKind::Press) {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    pub fn test_card_keys() {
+        assert_eq!(card_key(0), Some('1'));
+        assert_eq!(card_key(9), Some('0'));
+        assert_eq!(card_key(10), None);
+        for index in 0..10 {
+            let key = KeyCode::Char(card_key(index).unwrap());
+            assert_eq!(key_index(key), Some(index));
+        }
+        assert_eq!(key_index(KeyCode::Char('e')), None);
+    }
+}
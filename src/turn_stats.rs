@@ -1,12 +1,14 @@
 use crate::enum_map::EnumMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
 pub enum TurnStat {
     SpacesMoved,
+    SpacesMovedForced, // Spaces moved by being pushed or pulled. Not counted as SpacesMoved.
     AttackActions,
 }
 
-#[derive(Default, Debug, Clone, PartialEq, Eq)]
+#[derive(Default, Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct TurnStats(Vec<EnumMap<TurnStat>>);
 
 impl TurnStats {